@@ -1,6 +1,6 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
-use bevy_math::{prelude::*, *};
+use bevy_math::{cubic_splines::CubicCurve, prelude::*, *};
 
 fn easing(c: &mut Criterion) {
     let cubic_bezier = CubicSegment::new_bezier(vec2(0.25, 0.1), vec2(0.25, 1.0));
@@ -78,6 +78,50 @@ fn build_accel_cubic(c: &mut Criterion) {
     });
 }
 
+fn catmull_rom_vec3() -> CubicCurve<Vec3> {
+    CardinalSpline::new_catmull_rom(
+        (0..34)
+            .map(|i| {
+                let i = i as f32;
+                vec3(i, (i * 0.7).sin() * 4.0, (i * 0.3).cos() * 2.0)
+            })
+            .collect::<Vec<_>>(),
+    )
+    .to_curve()
+}
+
+fn arc_length(c: &mut Criterion) {
+    let curve = catmull_rom_vec3();
+    c.bench_function("build_arc_length_table_32_segments", |b| {
+        b.iter(|| {
+            black_box(
+                curve
+                    .clone()
+                    .with_arc_length_resolution(black_box(16))
+                    .arc_length(),
+            )
+        });
+    });
+    let length = curve.arc_length();
+    c.bench_function("position_at_distance_Vec3", |b| {
+        b.iter(|| black_box(curve.position_at_distance(black_box(length * 0.37))));
+    });
+}
+
+fn closest_point(c: &mut Criterion) {
+    let curve = catmull_rom_vec3();
+    c.bench_function("closest_point_32_segments", |b| {
+        b.iter(|| black_box(curve.closest_point(black_box(vec3(12.3, 1.0, -0.5)))));
+    });
+}
+
+fn subdivide(c: &mut Criterion) {
+    let curve = catmull_rom_vec3();
+    c.bench_function("subdivide_32_segments_by_4", |b| {
+        b.iter(|| black_box(curve.subdivide(black_box(4))));
+    });
+}
+
 criterion_group!(
     benches,
     easing,
@@ -86,5 +130,8 @@ criterion_group!(
     cubic,
     build_pos_cubic,
     build_accel_cubic,
+    arc_length,
+    closest_point,
+    subdivide,
 );
 criterion_main!(benches);
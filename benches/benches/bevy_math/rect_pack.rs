@@ -0,0 +1,38 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use bevy_math::{
+    rect_pack::{pack_rects, RectPacker},
+    UVec2,
+};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// Deterministic sizes between 1x1 and 32x32.
+fn sizes(count: usize) -> Vec<UVec2> {
+    let mut rng = ChaCha8Rng::seed_from_u64(42);
+    (0..count)
+        .map(|_| UVec2::new(rng.gen_range(1..=32), rng.gen_range(1..=32)))
+        .collect()
+}
+
+fn pack_1000(c: &mut Criterion) {
+    let sizes = sizes(1000);
+    c.bench_function("pack_rects_1000", |b| {
+        b.iter(|| black_box(pack_rects(black_box(&sizes), UVec2::splat(1024))));
+    });
+}
+
+fn insert_1000(c: &mut Criterion) {
+    let sizes = sizes(1000);
+    c.bench_function("rect_packer_insert_1000", |b| {
+        b.iter(|| {
+            let mut packer = RectPacker::new(UVec2::splat(1024)).with_padding(1);
+            for size in &sizes {
+                black_box(packer.insert(black_box(*size)));
+            }
+        });
+    });
+}
+
+criterion_group!(benches, pack_1000, insert_1000);
+criterion_main!(benches);
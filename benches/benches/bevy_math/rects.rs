@@ -0,0 +1,55 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use bevy_math::{prelude::*, *};
+
+/// Deterministic rectangles scattered over a 1000x1000 area.
+fn rects(count: usize) -> Vec<Rect> {
+    (0..count)
+        .map(|i| {
+            let i = i as f32;
+            let min = vec2((i * 37.3) % 1000.0, (i * 91.7) % 1000.0);
+            Rect::from_corners(min, min + vec2(10.0 + i % 40.0, 5.0 + i % 25.0))
+        })
+        .collect()
+}
+
+fn union(c: &mut Criterion) {
+    let rects = rects(1000);
+    c.bench_function("rect_union_1000", |b| {
+        b.iter(|| {
+            black_box(
+                black_box(&rects)
+                    .iter()
+                    .fold(rects[0], |bounds, rect| bounds.union(*rect)),
+            )
+        });
+    });
+}
+
+fn intersect(c: &mut Criterion) {
+    let rects = rects(1000);
+    let query = Rect::new(250.0, 250.0, 750.0, 750.0);
+    c.bench_function("rect_intersect_1000", |b| {
+        b.iter(|| {
+            black_box(&rects)
+                .iter()
+                .filter(|rect| !query.intersect(**rect).is_empty())
+                .count()
+        });
+    });
+}
+
+fn from_points(c: &mut Criterion) {
+    let points: Vec<Vec2> = (0..10_000)
+        .map(|i| {
+            let i = i as f32;
+            vec2((i * 0.37).sin() * 500.0, (i * 0.91).cos() * 500.0)
+        })
+        .collect();
+    c.bench_function("rect_from_points_10000", |b| {
+        b.iter(|| black_box(Rect::from_points(black_box(points.iter().copied()))));
+    });
+}
+
+criterion_group!(benches, union, intersect, from_points);
+criterion_main!(benches);
@@ -0,0 +1,56 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use bevy_math::{spatial_hash::SpatialHashGrid2d, Rect, Vec2};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// Deterministic rects of size 2x2 scattered over a 200x200 area.
+fn rects(count: usize) -> Vec<Rect> {
+    let mut rng = ChaCha8Rng::seed_from_u64(42);
+    (0..count)
+        .map(|_| {
+            let center = Vec2::new(rng.gen(), rng.gen()) * 200.0;
+            Rect::from_center_size(center, Vec2::splat(2.0))
+        })
+        .collect()
+}
+
+fn overlaps(a: Rect, b: Rect) -> bool {
+    a.min.cmple(b.max).all() && b.min.cmple(a.max).all()
+}
+
+fn neighbors_2000(c: &mut Criterion) {
+    let rects = rects(2000);
+    let mut group = c.benchmark_group("neighbors_2000");
+
+    group.bench_function("spatial_hash", |b| {
+        let mut grid = SpatialHashGrid2d::new(4.0);
+        b.iter(|| {
+            grid.clear();
+            for (i, rect) in rects.iter().enumerate() {
+                grid.insert(*rect, i);
+            }
+            let mut pairs = 0;
+            for rect in &rects {
+                pairs += grid.query_rect(rect.inset(1.0)).count();
+            }
+            black_box(pairs)
+        });
+    });
+
+    group.bench_function("naive", |b| {
+        b.iter(|| {
+            let mut pairs = 0;
+            for rect in &rects {
+                let area = rect.inset(1.0);
+                pairs += rects.iter().filter(|other| overlaps(**other, area)).count();
+            }
+            black_box(pairs)
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, neighbors_2000);
+criterion_main!(benches);
@@ -11,7 +11,10 @@ use bevy_asset::{AddAsset, Assets, Handle};
 use bevy_core::Name;
 use bevy_ecs::prelude::*;
 use bevy_hierarchy::{Children, Parent};
-use bevy_math::{Quat, Vec3};
+use bevy_math::{
+    cubic_splines::{CubicCurve, Point},
+    Quat, Vec3, Vec4,
+};
 use bevy_reflect::{Reflect, TypeUuid};
 use bevy_render::mesh::morph::MorphWeights;
 use bevy_time::Time;
@@ -44,19 +47,146 @@ pub enum Keyframes {
     ///
     /// [glTF design]: https://registry.khronos.org/glTF/specs/2.0/glTF-2.0.html#animations
     Weights(Vec<f32>),
+    /// Cubic Bezier control points for translation, one set of four per pair of consecutive
+    /// keyframe timestamps, so there is one more timestamp than sets of control points.
+    ///
+    /// Unlike [`Keyframes::Translation`], the curve is evaluated exactly at every sample instead
+    /// of interpolating linearly between keyframes. See [`VariableCurve::from_translation_curve`].
+    TranslationCurve(Vec<[Vec3; 4]>),
+    /// Cubic Bezier control points for rotation, one set of four per pair of consecutive
+    /// keyframe timestamps, with the components of a [`Quat`] as each control point. Like
+    /// [`Keyframes::TranslationCurve`], there is one more timestamp than sets of control points.
+    ///
+    /// The curve is evaluated exactly at every sample and then normalized.
+    /// See [`VariableCurve::from_rotation_curve`].
+    RotationCurve(Vec<[Vec4; 4]>),
 }
 
 /// Describes how an attribute of a [`Transform`] or [`MorphWeights`] should be animated.
 ///
-/// `keyframe_timestamps` and `keyframes` should have the same length.
+/// How many `keyframe_timestamps` there should be depends on the variant of `keyframes`:
+/// - [`Keyframes::Rotation`], [`Keyframes::Translation`] and [`Keyframes::Scale`] have one
+///   timestamp per keyframe.
+/// - [`Keyframes::Weights`] has one timestamp per `target_count` weights.
+/// - [`Keyframes::TranslationCurve`] and [`Keyframes::RotationCurve`] have one timestamp more than
+///   sets of control points, since each set spans the time between two timestamps.
 #[derive(Reflect, Clone, Debug)]
 pub struct VariableCurve {
-    /// Timestamp for each of the keyframes.
+    /// Timestamp for each of the keyframes, or for the start and end of each curve segment.
     pub keyframe_timestamps: Vec<f32>,
     /// List of the keyframes.
     pub keyframes: Keyframes,
 }
 
+impl VariableCurve {
+    /// Create a translation curve that plays `curve` from `start_time` over `duration` seconds.
+    ///
+    /// Each segment of the curve takes the same amount of time. The curve is evaluated directly
+    /// whenever the animation is sampled, which is smoother than baking it into keyframes.
+    ///
+    /// ```
+    /// # use bevy_animation::prelude::*;
+    /// # use bevy_core::Name;
+    /// # use bevy_math::{prelude::*, vec3};
+    /// let path = CardinalSpline::new_catmull_rom([
+    ///     vec3(-2.0, 0.0, 0.0),
+    ///     vec3(0.0, 0.0, 0.0),
+    ///     vec3(1.0, 2.0, 0.0),
+    ///     vec3(3.0, 0.0, 1.0),
+    ///     vec3(5.0, 0.0, 0.0),
+    /// ])
+    /// .to_curve();
+    ///
+    /// let mut clip = AnimationClip::default();
+    /// clip.add_curve_to_path(
+    ///     EntityPath {
+    ///         parts: vec![Name::new("camera")],
+    ///     },
+    ///     VariableCurve::from_translation_curve(&path, 0.0, 4.0),
+    /// );
+    /// assert_eq!(clip.duration(), 4.0);
+    /// ```
+    pub fn from_translation_curve(
+        curve: &CubicCurve<Vec3>,
+        start_time: f32,
+        duration: f32,
+    ) -> Self {
+        Self {
+            keyframe_timestamps: Self::segment_timestamps(
+                curve.segments().len(),
+                start_time,
+                duration,
+            ),
+            keyframes: Keyframes::TranslationCurve(
+                curve
+                    .segments()
+                    .iter()
+                    .map(|segment| segment.to_bezier_control_points())
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Create a rotation curve that plays `curve` from `start_time` over `duration` seconds.
+    ///
+    /// Each point of the curve holds the components of a [`Quat`], and is normalized after
+    /// evaluation. The curve is evaluated directly whenever the animation is sampled, which is
+    /// smoother than baking it into keyframes.
+    pub fn from_rotation_curve(curve: &CubicCurve<Vec4>, start_time: f32, duration: f32) -> Self {
+        Self {
+            keyframe_timestamps: Self::segment_timestamps(
+                curve.segments().len(),
+                start_time,
+                duration,
+            ),
+            keyframes: Keyframes::RotationCurve(
+                curve
+                    .segments()
+                    .iter()
+                    .map(|segment| segment.to_bezier_control_points())
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Evenly spaced timestamps for the start and end of each of `segment_count` segments.
+    fn segment_timestamps(segment_count: usize, start_time: f32, duration: f32) -> Vec<f32> {
+        if segment_count == 0 {
+            return Vec::new();
+        }
+        (0..=segment_count)
+            .map(|i| start_time + duration * i as f32 / segment_count as f32)
+            .collect()
+    }
+
+    /// Find the index of the keyframe that starts the interpolation at `seek_time`, and how far
+    /// `seek_time` is towards the next keyframe within `0..1`.
+    ///
+    /// Returns `None` if the curve hasn't started yet or is finished.
+    fn find_interpolation_start(&self, seek_time: f32) -> Option<(usize, f32)> {
+        // PERF: finding the current keyframe can be optimised
+        let step_start = match self
+            .keyframe_timestamps
+            .binary_search_by(|probe| probe.partial_cmp(&seek_time).unwrap())
+        {
+            Ok(n) if n >= self.keyframe_timestamps.len() - 1 => return None, // this curve is finished
+            Ok(i) => i,
+            Err(0) => return None, // this curve isn't started yet
+            Err(n) if n > self.keyframe_timestamps.len() - 1 => return None, // this curve is finished
+            Err(i) => i - 1,
+        };
+        let ts_start = self.keyframe_timestamps[step_start];
+        let ts_end = self.keyframe_timestamps[step_start + 1];
+        Some((step_start, (seek_time - ts_start) / (ts_end - ts_start)))
+    }
+}
+
+/// Evaluate the cubic Bezier curve with the given control points at `t`.
+fn cubic_bezier<P: Point>([p0, p1, p2, p3]: [P; 4], t: f32) -> P {
+    let u = 1.0 - t;
+    p0 * (u * u * u) + p1 * (3.0 * u * u * t) + p2 * (3.0 * u * t * t) + p3 * (t * t * t)
+}
+
 /// Path to an entity, with [`Name`]s. Each entity in a path must have a name.
 #[derive(Reflect, Clone, Debug, Hash, PartialEq, Eq, Default)]
 pub struct EntityPath {
@@ -661,25 +791,23 @@ fn apply_animation(
                                 lerp_morph_weights(morphs.weights_mut(), weight, keyframes, 0);
                             }
                         }
+                        Keyframes::TranslationCurve(segments) => {
+                            transform.translation =
+                                transform.translation.lerp(segments[0][0], weight);
+                        }
+                        Keyframes::RotationCurve(segments) => {
+                            let rot = Quat::from_vec4(segments[0][0]).normalize();
+                            transform.rotation = transform.rotation.slerp(rot, weight);
+                        }
                     }
                     continue;
                 }
 
                 // Find the current keyframe
-                // PERF: finding the current keyframe can be optimised
-                let step_start = match curve
-                    .keyframe_timestamps
-                    .binary_search_by(|probe| probe.partial_cmp(&animation.seek_time).unwrap())
-                {
-                    Ok(n) if n >= curve.keyframe_timestamps.len() - 1 => continue, // this curve is finished
-                    Ok(i) => i,
-                    Err(0) => continue, // this curve isn't started yet
-                    Err(n) if n > curve.keyframe_timestamps.len() - 1 => continue, // this curve is finished
-                    Err(i) => i - 1,
+                let Some((step_start, lerp)) = curve.find_interpolation_start(animation.seek_time)
+                else {
+                    continue;
                 };
-                let ts_start = curve.keyframe_timestamps[step_start];
-                let ts_end = curve.keyframe_timestamps[step_start + 1];
-                let lerp = (animation.seek_time - ts_start) / (ts_end - ts_start);
 
                 // Apply the keyframe
                 match &curve.keyframes {
@@ -711,6 +839,14 @@ fn apply_animation(
                             lerp_morph_weights(morphs.weights_mut(), weight, keyframes, step_start);
                         }
                     }
+                    Keyframes::TranslationCurve(segments) => {
+                        let result = cubic_bezier(segments[step_start], lerp);
+                        transform.translation = transform.translation.lerp(result, weight);
+                    }
+                    Keyframes::RotationCurve(segments) => {
+                        let rot = Quat::from_vec4(cubic_bezier(segments[step_start], lerp));
+                        transform.rotation = transform.rotation.slerp(rot.normalize(), weight);
+                    }
                 }
             }
         }
@@ -743,3 +879,88 @@ impl Plugin for AnimationPlugin {
             );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::{
+        cubic_splines::{CubicGenerator, Hermite},
+        vec3, vec4,
+    };
+
+    use super::*;
+
+    /// Sample a translation curve the same way `apply_animation` does.
+    fn sample_translation(curve: &VariableCurve, seek_time: f32) -> Option<Vec3> {
+        let (step_start, lerp) = curve.find_interpolation_start(seek_time)?;
+        match &curve.keyframes {
+            Keyframes::Translation(keyframes) => {
+                Some(keyframes[step_start].lerp(keyframes[step_start + 1], lerp))
+            }
+            Keyframes::TranslationCurve(segments) => Some(cubic_bezier(segments[step_start], lerp)),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn translation_curve_is_evaluated_exactly() {
+        let spline = Hermite::new(
+            [
+                vec3(0.0, 0.0, 0.0),
+                vec3(2.0, 1.0, 0.0),
+                vec3(4.0, 0.0, -2.0),
+            ],
+            [
+                vec3(3.0, 0.0, 0.0),
+                vec3(0.0, 3.0, 0.0),
+                vec3(0.0, 0.0, 5.0),
+            ],
+        )
+        .to_curve();
+        // The two segments play from 1 to 5 seconds.
+        let direct = VariableCurve::from_translation_curve(&spline, 1.0, 4.0);
+        assert_eq!(direct.keyframe_timestamps, vec![1.0, 3.0, 5.0]);
+
+        // The same curve baked into four keyframes per segment.
+        let baked = VariableCurve {
+            keyframe_timestamps: (0..=8).map(|i| 1.0 + i as f32 * 0.5).collect(),
+            keyframes: Keyframes::Translation(spline.iter_positions(8).collect()),
+        };
+
+        let (mut direct_error, mut baked_error) = (0.0f32, 0.0f32);
+        for i in 0..100 {
+            let seek_time = 1.0 + 4.0 * (i as f32 + 0.5) / 100.0;
+            let expected = spline.position((seek_time - 1.0) / 2.0);
+            let direct = sample_translation(&direct, seek_time).unwrap();
+            let baked = sample_translation(&baked, seek_time).unwrap();
+            direct_error = direct_error.max(direct.distance(expected));
+            baked_error = baked_error.max(baked.distance(expected));
+        }
+        assert!(direct_error < 1e-5);
+        assert!(direct_error < baked_error);
+
+        // Outside of the clip range the curve isn't applied.
+        assert_eq!(sample_translation(&direct, 0.5), None);
+        assert_eq!(sample_translation(&direct, 5.5), None);
+    }
+
+    #[test]
+    fn rotation_curve_control_points() {
+        let start = Quat::from_rotation_y(0.5);
+        let end = Quat::from_rotation_y(1.5);
+        let spline = Hermite::new(
+            [Vec4::from(start), Vec4::from(end)],
+            [vec4(0.0, 0.0, 0.0, 0.0); 2],
+        )
+        .to_curve();
+        let curve = VariableCurve::from_rotation_curve(&spline, 0.0, 1.0);
+        let Keyframes::RotationCurve(segments) = &curve.keyframes else {
+            panic!("expected a rotation curve");
+        };
+        assert_eq!(segments.len(), 1);
+        let halfway = Quat::from_vec4(cubic_bezier(segments[0], 0.5)).normalize();
+        assert!(halfway.angle_between(Quat::from_rotation_y(1.0)) < 1e-4);
+
+        let empty = VariableCurve::from_rotation_curve(&Default::default(), 0.0, 1.0);
+        assert!(empty.keyframe_timestamps.is_empty());
+    }
+}
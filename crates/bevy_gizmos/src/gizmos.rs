@@ -9,12 +9,19 @@ use bevy_ecs::{
 use bevy_math::{Mat2, Quat, Vec2, Vec3};
 use bevy_render::color::Color;
 use bevy_transform::TransformPoint;
+use bevy_utils::tracing::warn;
+
+use crate::GizmoConfig;
 
 type PositionItem = [f32; 3];
 type ColorItem = [f32; 4];
 
 const DEFAULT_CIRCLE_SEGMENTS: usize = 32;
 
+/// The maximum number of points a single call to [`Gizmos::points`] or [`Gizmos::points_2d`]
+/// draws. Any further points are dropped with a warning.
+pub const MAX_POINTS_PER_CALL: usize = 1 << 16;
+
 #[derive(Resource, Default)]
 pub(crate) struct GizmoStorage {
     pub list_positions: Vec<PositionItem>,
@@ -39,10 +46,70 @@ struct GizmoBuffer {
     list_colors: Vec<ColorItem>,
     strip_positions: Vec<PositionItem>,
     strip_colors: Vec<ColorItem>,
+    curves: Vec<CurveRequest>,
+}
+
+/// A circle or arc without an explicit number of line-segments.
+///
+/// The segments are derived from the [`GizmoConfig`] when the buffer is applied, so that
+/// [`Gizmos`] doesn't access the config and can be used next to `ResMut<GizmoConfig>`.
+struct CurveRequest {
+    position: Vec3,
+    rotation: Quat,
+    radius: f32,
+    /// The direction and angle of an arc, or `None` for a full circle.
+    arc: Option<(f32, f32)>,
+    color: Color,
+}
+
+impl GizmoBuffer {
+    fn linestrip(&mut self, positions: impl IntoIterator<Item = Vec3>, color: Color) {
+        self.strip_positions.extend(
+            positions
+                .into_iter()
+                .map(|vec3| vec3.to_array())
+                .chain(iter::once([f32::NAN; 3])),
+        );
+        let len = self.strip_positions.len();
+        self.strip_colors
+            .resize(len - 1, color.as_linear_rgba_f32());
+        self.strip_colors.push([f32::NAN; 4]);
+    }
+
+    /// Draws the requested curves as line strips, with segments scaled by `config`.
+    fn resolve_curves(&mut self, config: &GizmoConfig) {
+        for curve in std::mem::take(&mut self.curves) {
+            let to_world = |vec2: Vec2| curve.position + curve.rotation * vec2.extend(0.);
+            match curve.arc {
+                None => {
+                    let segments = config.scale_curved_segments(DEFAULT_CIRCLE_SEGMENTS as f32);
+                    let positions = circle_inner(curve.radius, segments).map(to_world);
+                    self.linestrip(positions, curve.color);
+                }
+                Some((direction_angle, arc_angle)) => {
+                    // Do a linear interpolation between 1 and `DEFAULT_CIRCLE_SEGMENTS`
+                    // using the arc angle as scalar, then apply the configured resolution scale.
+                    let segments = config.scale_curved_segments(
+                        (arc_angle.abs() / TAU) * DEFAULT_CIRCLE_SEGMENTS as f32,
+                    );
+                    let positions =
+                        arc_inner(direction_angle, arc_angle, curve.radius, segments).map(to_world);
+                    self.linestrip(positions, curve.color);
+                }
+            }
+        }
+    }
 }
 
 impl SystemBuffer for GizmoBuffer {
     fn apply(&mut self, _system_meta: &SystemMeta, world: &mut World) {
+        if !self.curves.is_empty() {
+            match world.get_resource::<GizmoConfig>() {
+                Some(config) => self.resolve_curves(config),
+                None => self.resolve_curves(&GizmoConfig::default()),
+            }
+        }
+
         let mut storage = world.resource_mut::<GizmoStorage>();
         storage.list_positions.append(&mut self.list_positions);
         storage.list_colors.append(&mut self.list_colors);
@@ -152,12 +219,7 @@ impl<'s> Gizmos<'s> {
     /// ```
     #[inline]
     pub fn linestrip(&mut self, positions: impl IntoIterator<Item = Vec3>, color: Color) {
-        self.extend_strip_positions(positions);
-        let len = self.buffer.strip_positions.len();
-        self.buffer
-            .strip_colors
-            .resize(len - 1, color.as_linear_rgba_f32());
-        self.buffer.strip_colors.push([f32::NAN; 4]);
+        self.buffer.linestrip(positions, color);
     }
 
     /// Draw a line in 3D made of straight segments between the points, with a color gradient.
@@ -213,7 +275,8 @@ impl<'s> Gizmos<'s> {
     /// fn system(mut gizmos: Gizmos) {
     ///     gizmos.circle(Vec3::ZERO, Vec3::Z, 1., Color::GREEN);
     ///
-    ///     // Circles have 32 line-segments by default.
+    ///     // Circles have 32 line-segments by default, scaled by
+    ///     // `GizmoConfig::curved_resolution_scale`.
     ///     // You may want to increase this for larger circles.
     ///     gizmos
     ///         .circle(Vec3::ZERO, Vec3::Z, 5., Color::RED)
@@ -235,7 +298,7 @@ impl<'s> Gizmos<'s> {
             normal,
             radius,
             color,
-            segments: DEFAULT_CIRCLE_SEGMENTS,
+            segments: None,
         }
     }
 
@@ -251,7 +314,8 @@ impl<'s> Gizmos<'s> {
     /// fn system(mut gizmos: Gizmos) {
     ///     gizmos.sphere(Vec3::ZERO, Quat::IDENTITY, 1., Color::BLACK);
     ///
-    ///     // Each circle has 32 line-segments by default.
+    ///     // Each circle has 32 line-segments by default, scaled by
+    ///     // `GizmoConfig::curved_resolution_scale`.
     ///     // You may want to increase this for larger spheres.
     ///     gizmos
     ///         .sphere(Vec3::ZERO, Quat::IDENTITY, 5., Color::BLACK)
@@ -273,7 +337,7 @@ impl<'s> Gizmos<'s> {
             rotation,
             radius,
             color,
-            circle_segments: DEFAULT_CIRCLE_SEGMENTS,
+            circle_segments: None,
         }
     }
 
@@ -332,6 +396,42 @@ impl<'s> Gizmos<'s> {
         self.add_list_color(color, 6);
     }
 
+    /// Draw a small cross of the given `size` at each of the `points` in 3D.
+    ///
+    /// Each point is drawn as three axis-aligned line-segments, and all points of a call are
+    /// batched together. This is useful for visualizing point clouds, e.g. sampled positions.
+    /// At most [`MAX_POINTS_PER_CALL`] points are drawn per call.
+    ///
+    /// This should be called for each frame the points need to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.points([Vec3::ZERO, Vec3::X, Vec3::Y], 0.1, Color::GREEN);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn points(&mut self, points: impl IntoIterator<Item = Vec3>, size: f32, color: Color) {
+        let [x, y, z] = [Vec3::X, Vec3::Y, Vec3::Z].map(|axis| axis * size / 2.);
+        let start = self.buffer.list_positions.len();
+        self.extend_list_positions(cap_points(points).flat_map(|point| {
+            [
+                point - x,
+                point + x,
+                point - y,
+                point + y,
+                point - z,
+                point + z,
+            ]
+        }));
+        let added = self.buffer.list_positions.len() - start;
+        self.add_list_color(color, added);
+    }
+
     /// Draw a line in 2D from `start` to `end`.
     ///
     /// This should be called for each frame the line needs to be rendered.
@@ -478,7 +578,8 @@ impl<'s> Gizmos<'s> {
     /// fn system(mut gizmos: Gizmos) {
     ///     gizmos.circle_2d(Vec2::ZERO, 1., Color::GREEN);
     ///
-    ///     // Circles have 32 line-segments by default.
+    ///     // Circles have 32 line-segments by default, scaled by
+    ///     // `GizmoConfig::curved_resolution_scale`.
     ///     // You may want to increase this for larger circles.
     ///     gizmos
     ///         .circle_2d(Vec2::ZERO, 5., Color::RED)
@@ -498,7 +599,7 @@ impl<'s> Gizmos<'s> {
             position,
             radius,
             color,
-            segments: DEFAULT_CIRCLE_SEGMENTS,
+            segments: None,
         }
     }
 
@@ -522,7 +623,8 @@ impl<'s> Gizmos<'s> {
     /// fn system(mut gizmos: Gizmos) {
     ///     gizmos.arc_2d(Vec2::ZERO, 0., PI / 4., 1., Color::GREEN);
     ///
-    ///     // Arcs have 32 line-segments by default.
+    ///     // Full arcs have 32 line-segments by default, scaled by
+    ///     // `GizmoConfig::curved_resolution_scale`.
     ///     // You may want to increase this for larger arcs.
     ///     gizmos
     ///         .arc_2d(Vec2::ZERO, 0., PI / 4., 5., Color::RED)
@@ -571,6 +673,55 @@ impl<'s> Gizmos<'s> {
         self.linestrip_2d([tl, tr, br, bl, tl], color);
     }
 
+    /// Draw a small cross of the given `size` at each of the `points` in 2D.
+    ///
+    /// Each point is drawn as two axis-aligned line-segments, and all points of a call are
+    /// batched together. At most [`MAX_POINTS_PER_CALL`] points are drawn per call.
+    ///
+    /// This should be called for each frame the points need to be rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_render::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.points_2d([Vec2::ZERO, Vec2::X, Vec2::Y], 4., Color::GREEN);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn points_2d(&mut self, points: impl IntoIterator<Item = Vec2>, size: f32, color: Color) {
+        let [x, y] = [Vec2::X, Vec2::Y].map(|axis| axis * size / 2.);
+        let start = self.buffer.list_positions.len();
+        self.extend_list_positions(
+            cap_points(points)
+                .flat_map(|point| [point - x, point + x, point - y, point + y])
+                .map(|vec2| vec2.extend(0.)),
+        );
+        let added = self.buffer.list_positions.len() - start;
+        self.add_list_color(color, added);
+    }
+
+    /// Draws a circle or arc whose segments are derived from the [`GizmoConfig`] later.
+    #[inline]
+    fn curve(
+        &mut self,
+        position: Vec3,
+        rotation: Quat,
+        radius: f32,
+        arc: Option<(f32, f32)>,
+        color: Color,
+    ) {
+        self.buffer.curves.push(CurveRequest {
+            position,
+            rotation,
+            radius,
+            arc,
+            color,
+        });
+    }
+
     #[inline]
     fn extend_list_positions(&mut self, positions: impl IntoIterator<Item = Vec3>) {
         self.buffer
@@ -591,16 +742,6 @@ impl<'s> Gizmos<'s> {
             .list_colors
             .extend(iter::repeat(color.as_linear_rgba_f32()).take(count));
     }
-
-    #[inline]
-    fn extend_strip_positions(&mut self, positions: impl IntoIterator<Item = Vec3>) {
-        self.buffer.strip_positions.extend(
-            positions
-                .into_iter()
-                .map(|vec3| vec3.to_array())
-                .chain(iter::once([f32::NAN; 3])),
-        );
-    }
 }
 
 /// A builder returned by [`Gizmos::circle`].
@@ -610,13 +751,13 @@ pub struct CircleBuilder<'a, 's> {
     normal: Vec3,
     radius: f32,
     color: Color,
-    segments: usize,
+    segments: Option<usize>,
 }
 
 impl CircleBuilder<'_, '_> {
     /// Set the number of line-segments for this circle.
     pub fn segments(mut self, segments: usize) -> Self {
-        self.segments = segments;
+        self.segments = Some(segments);
         self
     }
 }
@@ -624,7 +765,12 @@ impl CircleBuilder<'_, '_> {
 impl Drop for CircleBuilder<'_, '_> {
     fn drop(&mut self) {
         let rotation = Quat::from_rotation_arc(Vec3::Z, self.normal);
-        let positions = circle_inner(self.radius, self.segments)
+        let Some(segments) = self.segments else {
+            self.gizmos
+                .curve(self.position, rotation, self.radius, None, self.color);
+            return;
+        };
+        let positions = circle_inner(self.radius, segments)
             .map(|vec2| (self.position + rotation * vec2.extend(0.)));
         self.gizmos.linestrip(positions, self.color);
     }
@@ -637,13 +783,13 @@ pub struct SphereBuilder<'a, 's> {
     rotation: Quat,
     radius: f32,
     color: Color,
-    circle_segments: usize,
+    circle_segments: Option<usize>,
 }
 
 impl SphereBuilder<'_, '_> {
     /// Set the number of line-segments per circle for this sphere.
     pub fn circle_segments(mut self, segments: usize) -> Self {
-        self.circle_segments = segments;
+        self.circle_segments = Some(segments);
         self
     }
 }
@@ -651,9 +797,10 @@ impl SphereBuilder<'_, '_> {
 impl Drop for SphereBuilder<'_, '_> {
     fn drop(&mut self) {
         for axis in Vec3::AXES {
-            self.gizmos
-                .circle(self.position, self.rotation * axis, self.radius, self.color)
-                .segments(self.circle_segments);
+            let mut circle =
+                self.gizmos
+                    .circle(self.position, self.rotation * axis, self.radius, self.color);
+            circle.segments = self.circle_segments;
         }
     }
 }
@@ -664,20 +811,26 @@ pub struct Circle2dBuilder<'a, 's> {
     position: Vec2,
     radius: f32,
     color: Color,
-    segments: usize,
+    segments: Option<usize>,
 }
 
 impl Circle2dBuilder<'_, '_> {
     /// Set the number of line-segments for this circle.
     pub fn segments(mut self, segments: usize) -> Self {
-        self.segments = segments;
+        self.segments = Some(segments);
         self
     }
 }
 
 impl Drop for Circle2dBuilder<'_, '_> {
     fn drop(&mut self) {
-        let positions = circle_inner(self.radius, self.segments).map(|vec2| (vec2 + self.position));
+        let Some(segments) = self.segments else {
+            let position = self.position.extend(0.);
+            self.gizmos
+                .curve(position, Quat::IDENTITY, self.radius, None, self.color);
+            return;
+        };
+        let positions = circle_inner(self.radius, segments).map(|vec2| (vec2 + self.position));
         self.gizmos.linestrip_2d(positions, self.color);
     }
 }
@@ -703,11 +856,12 @@ impl Arc2dBuilder<'_, '_> {
 
 impl Drop for Arc2dBuilder<'_, '_> {
     fn drop(&mut self) {
-        let segments = match self.segments {
-            Some(segments) => segments,
-            // Do a linear interpolation between 1 and `DEFAULT_CIRCLE_SEGMENTS`
-            // using the arc angle as scalar.
-            None => ((self.arc_angle.abs() / TAU) * DEFAULT_CIRCLE_SEGMENTS as f32).ceil() as usize,
+        let Some(segments) = self.segments else {
+            let arc = Some((self.direction_angle, self.arc_angle));
+            let position = self.position.extend(0.);
+            self.gizmos
+                .curve(position, Quat::IDENTITY, self.radius, arc, self.color);
+            return;
         };
 
         let positions = arc_inner(self.direction_angle, self.arc_angle, self.radius, segments)
@@ -737,6 +891,21 @@ fn circle_inner(radius: f32, segments: usize) -> impl Iterator<Item = Vec2> {
     })
 }
 
+/// Limits `points` to [`MAX_POINTS_PER_CALL`] items, warning if any are dropped.
+fn cap_points<T>(points: impl IntoIterator<Item = T>) -> impl Iterator<Item = T> {
+    points.into_iter().enumerate().map_while(|(i, point)| {
+        if i < MAX_POINTS_PER_CALL {
+            Some(point)
+        } else {
+            warn!(
+                "Gizmos can draw at most {MAX_POINTS_PER_CALL} points per call, \
+                ignoring the remaining points"
+            );
+            None
+        }
+    })
+}
+
 fn rect_inner(size: Vec2) -> [Vec2; 4] {
     let half_size = size / 2.;
     let tl = Vec2::new(-half_size.x, half_size.y);
@@ -745,3 +914,189 @@ fn rect_inner(size: Vec2) -> [Vec2; 4] {
     let br = Vec2::new(half_size.x, -half_size.y);
     [tl, tr, br, bl]
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{
+        schedule::{IntoSystemConfigs, Schedule},
+        system::ResMut,
+        world::World,
+    };
+
+    use super::*;
+
+    /// Runs `system` once and returns the world containing the drawn gizmos.
+    fn run_gizmo_system<M>(config: GizmoConfig, system: impl IntoSystemConfigs<M>) -> World {
+        let mut world = World::new();
+        world.insert_resource(config);
+        world.init_resource::<GizmoStorage>();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(system);
+        schedule.run(&mut world);
+        world
+    }
+
+    /// Runs `system` once and returns the number of positions (excluding separators) it emitted
+    /// as line strips.
+    fn strip_positions_drawn<M>(config: GizmoConfig, system: impl IntoSystemConfigs<M>) -> usize {
+        run_gizmo_system(config, system)
+            .resource::<GizmoStorage>()
+            .strip_positions
+            .iter()
+            .filter(|position| !position[0].is_nan())
+            .count()
+    }
+
+    fn config_with_scale(curved_resolution_scale: f32) -> GizmoConfig {
+        GizmoConfig {
+            curved_resolution_scale,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn default_circle_segments() {
+        let drawn = strip_positions_drawn(GizmoConfig::default(), |mut gizmos: Gizmos| {
+            gizmos.circle_2d(Vec2::ZERO, 1., Color::WHITE);
+        });
+        assert_eq!(drawn, DEFAULT_CIRCLE_SEGMENTS + 1);
+    }
+
+    #[test]
+    fn resolution_scale_changes_segments() {
+        let drawn = strip_positions_drawn(config_with_scale(2.), |mut gizmos: Gizmos| {
+            gizmos.circle(Vec3::ZERO, Vec3::Z, 1., Color::WHITE);
+        });
+        assert_eq!(drawn, 2 * DEFAULT_CIRCLE_SEGMENTS + 1);
+
+        let drawn = strip_positions_drawn(config_with_scale(0.25), |mut gizmos: Gizmos| {
+            gizmos.sphere(Vec3::ZERO, Quat::IDENTITY, 1., Color::WHITE);
+        });
+        assert_eq!(drawn, 3 * (DEFAULT_CIRCLE_SEGMENTS / 4 + 1));
+
+        // A half arc uses half of the scaled circle segments.
+        let drawn = strip_positions_drawn(config_with_scale(2.), |mut gizmos: Gizmos| {
+            gizmos.arc_2d(Vec2::ZERO, 0., TAU / 2., 1., Color::WHITE);
+        });
+        assert_eq!(drawn, DEFAULT_CIRCLE_SEGMENTS + 1);
+    }
+
+    #[test]
+    fn resolution_scale_is_clamped() {
+        let config = GizmoConfig {
+            curved_resolution_scale: 0.,
+            curved_min_segments: 6,
+            ..Default::default()
+        };
+        let drawn = strip_positions_drawn(config, |mut gizmos: Gizmos| {
+            gizmos.circle_2d(Vec2::ZERO, 1., Color::WHITE);
+        });
+        assert_eq!(drawn, 6 + 1);
+
+        let config = GizmoConfig {
+            curved_resolution_scale: 100.,
+            curved_max_segments: 48,
+            ..Default::default()
+        };
+        let drawn = strip_positions_drawn(config, |mut gizmos: Gizmos| {
+            gizmos.circle_2d(Vec2::ZERO, 1., Color::WHITE);
+        });
+        assert_eq!(drawn, 48 + 1);
+    }
+
+    #[test]
+    fn explicit_segments_ignore_resolution_scale() {
+        let config = GizmoConfig {
+            curved_resolution_scale: 3.,
+            curved_max_segments: 8,
+            ..Default::default()
+        };
+        let drawn = strip_positions_drawn(config.clone(), |mut gizmos: Gizmos| {
+            gizmos.circle_2d(Vec2::ZERO, 1., Color::WHITE).segments(10);
+        });
+        assert_eq!(drawn, 10 + 1);
+
+        let drawn = strip_positions_drawn(config.clone(), |mut gizmos: Gizmos| {
+            gizmos
+                .sphere(Vec3::ZERO, Quat::IDENTITY, 1., Color::WHITE)
+                .circle_segments(10);
+        });
+        assert_eq!(drawn, 3 * (10 + 1));
+
+        let drawn = strip_positions_drawn(config, |mut gizmos: Gizmos| {
+            gizmos
+                .arc_2d(Vec2::ZERO, 0., TAU / 4., 1., Color::WHITE)
+                .segments(10);
+        });
+        assert_eq!(drawn, 10 + 1);
+    }
+
+    #[test]
+    fn gizmos_do_not_borrow_the_config() {
+        // Toggling gizmo settings from a system that also draws gizmos is allowed.
+        let config = GizmoConfig {
+            curved_resolution_scale: 2.,
+            ..Default::default()
+        };
+        let drawn = strip_positions_drawn(
+            config,
+            |mut gizmos: Gizmos, mut config: ResMut<GizmoConfig>| {
+                gizmos.circle_2d(Vec2::ZERO, 1., Color::WHITE);
+                config.curved_resolution_scale = 0.5;
+            },
+        );
+        // The config is read when the gizmos are applied, after the system changed it.
+        assert_eq!(drawn, DEFAULT_CIRCLE_SEGMENTS / 2 + 1);
+
+        // Without a config, the default resolution is used.
+        let mut world = World::new();
+        world.init_resource::<GizmoStorage>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(|mut gizmos: Gizmos| {
+            gizmos.sphere(Vec3::ZERO, Quat::IDENTITY, 1., Color::WHITE);
+        });
+        schedule.run(&mut world);
+        let storage = world.resource::<GizmoStorage>();
+        assert_eq!(
+            storage.strip_positions.len(),
+            3 * (DEFAULT_CIRCLE_SEGMENTS + 2)
+        );
+        assert_eq!(storage.strip_colors.len(), storage.strip_positions.len());
+    }
+
+    #[test]
+    fn points_are_batched_line_lists() {
+        let world = run_gizmo_system(GizmoConfig::default(), |mut gizmos: Gizmos| {
+            gizmos.points((0..10).map(|i| Vec3::X * i as f32), 0.5, Color::WHITE);
+            gizmos.points_2d((0..5).map(|i| Vec2::Y * i as f32), 2., Color::RED);
+        });
+        let storage = world.resource::<GizmoStorage>();
+
+        // Three line-segments per 3D point and two per 2D point, without any line strips.
+        assert_eq!(storage.list_positions.len(), 10 * 6 + 5 * 4);
+        assert_eq!(storage.list_colors.len(), storage.list_positions.len());
+        assert!(storage.strip_positions.is_empty());
+
+        assert_eq!(storage.list_positions[0], [-0.25, 0., 0.]);
+        assert_eq!(storage.list_positions[5], [0., 0., 0.25]);
+        assert_eq!(storage.list_positions[60], [-1., 0., 0.]);
+        assert_eq!(storage.list_positions[63], [0., 1., 0.]);
+        assert_eq!(storage.list_colors[60], Color::RED.as_linear_rgba_f32(),);
+    }
+
+    #[test]
+    fn points_are_capped_per_call() {
+        let world = run_gizmo_system(GizmoConfig::default(), |mut gizmos: Gizmos| {
+            gizmos.points_2d(
+                iter::repeat(Vec2::ZERO).take(MAX_POINTS_PER_CALL + 10),
+                1.,
+                Color::WHITE,
+            );
+            gizmos.points(iter::empty(), 1., Color::WHITE);
+        });
+        let storage = world.resource::<GizmoStorage>();
+        assert_eq!(storage.list_positions.len(), MAX_POINTS_PER_CALL * 4);
+        assert_eq!(storage.list_colors.len(), MAX_POINTS_PER_CALL * 4);
+    }
+}
@@ -173,6 +173,25 @@ pub struct GizmoConfig {
     ///
     /// Gizmos will only be rendered to cameras with intersecting layers.
     pub render_layers: RenderLayers,
+    /// Multiplier applied to the default number of line-segments of curved gizmos,
+    /// such as circles, arcs and spheres.
+    ///
+    /// Values below `1.0` trade smoothness for performance, values above `1.0` do the opposite.
+    /// Segment counts set explicitly on a gizmo builder, e.g. with `.segments(64)`,
+    /// are used as-is and ignore this setting.
+    ///
+    /// Defaults to `1.0`.
+    pub curved_resolution_scale: f32,
+    /// The minimum number of line-segments a curved gizmo is drawn with when its
+    /// segment count is derived from [`curved_resolution_scale`](Self::curved_resolution_scale).
+    ///
+    /// Defaults to `1`.
+    pub curved_min_segments: usize,
+    /// The maximum number of line-segments a curved gizmo is drawn with when its
+    /// segment count is derived from [`curved_resolution_scale`](Self::curved_resolution_scale).
+    ///
+    /// Defaults to `1024`.
+    pub curved_max_segments: usize,
 }
 
 impl Default for GizmoConfig {
@@ -184,10 +203,23 @@ impl Default for GizmoConfig {
             depth_bias: 0.,
             aabb: Default::default(),
             render_layers: Default::default(),
+            curved_resolution_scale: 1.,
+            curved_min_segments: 1,
+            curved_max_segments: 1024,
         }
     }
 }
 
+impl GizmoConfig {
+    /// Scales a default segment count by [`curved_resolution_scale`](Self::curved_resolution_scale)
+    /// and clamps it to the configured segment range.
+    pub(crate) fn scale_curved_segments(&self, segments: f32) -> usize {
+        let min = self.curved_min_segments;
+        let max = self.curved_max_segments.max(min);
+        ((segments * self.curved_resolution_scale.max(0.)).ceil() as usize).clamp(min, max)
+    }
+}
+
 /// Configuration for drawing the [`Aabb`] component on entities.
 #[derive(Clone, Default)]
 pub struct AabbGizmoConfig {
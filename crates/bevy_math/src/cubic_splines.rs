@@ -1,15 +1,21 @@
 //! Provides types for building cubic splines for rendering curves and use with animation easing.
 
-use glam::{Vec2, Vec3, Vec3A};
+use glam::{Mat3, Quat, Vec2, Vec3, Vec3A, Vec4};
+
+use crate::float_ord::nearest_by_key;
 
 use std::{
     fmt::Debug,
     iter::Sum,
     ops::{Add, Mul, Sub},
+    sync::OnceLock,
 };
 
 /// A point in space of any dimension that supports the math ops needed for cubic spline
 /// interpolation.
+///
+/// Besides positions, this is implemented for `f32` and [`Vec4`], so curves can also be used to
+/// animate scalar values such as intensities, or colors and other four-component values.
 pub trait Point:
     Mul<f32, Output = Self>
     + Add<Self, Output = Self>
@@ -25,9 +31,52 @@ pub trait Point:
 }
 impl Point for Vec3 {}
 impl Point for Vec3A {}
+impl Point for Vec4 {}
 impl Point for Vec2 {}
 impl Point for f32 {}
 
+/// A [`Point`] that can be measured, which is needed to compute distances along a curve.
+///
+/// This is required by the methods of [`CubicCurve`] and [`CubicSegment`] that measure arc
+/// lengths or find the closest point to a position, but not to build or sample curves.
+pub trait NormedPoint: Point {
+    /// The length of this point interpreted as a vector.
+    fn length(self) -> f32;
+
+    /// The dot product of this point and `rhs` interpreted as vectors.
+    fn dot(self, rhs: Self) -> f32;
+}
+
+macro_rules! impl_normed_point_for_vec {
+    ($($vec:ty),*) => {$(
+        impl NormedPoint for $vec {
+            #[inline]
+            fn length(self) -> f32 {
+                <$vec>::length(self)
+            }
+
+            #[inline]
+            fn dot(self, rhs: Self) -> f32 {
+                <$vec>::dot(self, rhs)
+            }
+        }
+    )*};
+}
+
+impl_normed_point_for_vec!(Vec3, Vec3A, Vec4, Vec2);
+
+impl NormedPoint for f32 {
+    #[inline]
+    fn length(self) -> f32 {
+        self.abs()
+    }
+
+    #[inline]
+    fn dot(self, rhs: Self) -> f32 {
+        self * rhs
+    }
+}
+
 /// A spline composed of a single cubic Bezier curve.
 ///
 /// Useful for user-drawn curves with local control, or animation easing. See
@@ -67,24 +116,25 @@ impl<P: Point> CubicBezier<P> {
             control_points: control_points.into(),
         }
     }
+
+    /// The characteristic matrix of cubic Bezier curves.
+    const CHAR_MATRIX: [[f32; 4]; 4] = [
+        [1., 0., 0., 0.],
+        [-3., 3., 0., 0.],
+        [3., -6., 3., 0.],
+        [-1., 3., -3., 1.],
+    ];
 }
 impl<P: Point> CubicGenerator<P> for CubicBezier<P> {
     #[inline]
     fn to_curve(&self) -> CubicCurve<P> {
-        let char_matrix = [
-            [1., 0., 0., 0.],
-            [-3., 3., 0., 0.],
-            [3., -6., 3., 0.],
-            [-1., 3., -3., 1.],
-        ];
-
         let segments = self
             .control_points
             .iter()
-            .map(|p| CubicCurve::coefficients(*p, 1.0, char_matrix))
+            .map(|p| CubicCurve::coefficients(*p, 1.0, Self::CHAR_MATRIX))
             .collect();
 
-        CubicCurve { segments }
+        CubicCurve::from_segments(segments)
     }
 }
 
@@ -156,7 +206,7 @@ impl<P: Point> CubicGenerator<P> for Hermite<P> {
             })
             .collect();
 
-        CubicCurve { segments }
+        CubicCurve::from_segments(segments)
     }
 }
 
@@ -226,7 +276,7 @@ impl<P: Point> CubicGenerator<P> for CardinalSpline<P> {
             .map(|p| CubicCurve::coefficients([p[0], p[1], p[2], p[3]], 1.0, char_matrix))
             .collect();
 
-        CubicCurve { segments }
+        CubicCurve::from_segments(segments)
     }
 }
 
@@ -282,7 +332,117 @@ impl<P: Point> CubicGenerator<P> for BSpline<P> {
             .map(|p| CubicCurve::coefficients([p[0], p[1], p[2], p[3]], 1.0 / 6.0, char_matrix))
             .collect();
 
-        CubicCurve { segments }
+        CubicCurve::from_segments(segments)
+    }
+}
+
+/// A spline composed of quadratic Bezier curves, as commonly found in font outlines and SVG
+/// paths.
+///
+/// Each quadratic curve is converted to an equivalent cubic segment by degree elevation, which
+/// traces exactly the same curve.
+///
+/// ### Interpolation
+/// The curve only passes through the first and last control point in each set of three points.
+///
+/// ### Tangency
+/// Manually defined by the intermediate control point within each set of three points.
+///
+/// ### Continuity
+/// At minimum C0 continuous, up to C1 if the intermediate control points of neighboring curves
+/// are mirrored around their shared point.
+///
+/// ### Usage
+///
+/// ```
+/// # use bevy_math::{*, prelude::*};
+/// let points = [[
+///     vec2(-1.0, -20.0),
+///     vec2(3.0, 2.0),
+///     vec2(9.0, 8.0),
+/// ]];
+/// let bezier = QuadraticBezier::new(points).to_curve();
+/// let positions: Vec<_> = bezier.iter_positions(100).collect();
+/// ```
+pub struct QuadraticBezier<P: Point> {
+    control_points: Vec<[P; 3]>,
+}
+
+impl<P: Point> QuadraticBezier<P> {
+    /// Create a new quadratic Bezier curve from sets of control points.
+    pub fn new(control_points: impl Into<Vec<[P; 3]>>) -> Self {
+        Self {
+            control_points: control_points.into(),
+        }
+    }
+}
+impl<P: Point> CubicGenerator<P> for QuadraticBezier<P> {
+    #[inline]
+    fn to_curve(&self) -> CubicCurve<P> {
+        let cubic_control_points: Vec<[P; 4]> = self
+            .control_points
+            .iter()
+            .map(|&[p0, p1, p2]| {
+                [
+                    p0,
+                    p0 + (p1 - p0) * (2.0 / 3.0),
+                    p2 + (p1 - p2) * (2.0 / 3.0),
+                    p2,
+                ]
+            })
+            .collect();
+        CubicBezier::new(cubic_control_points).to_curve()
+    }
+}
+
+/// A spline that moves in a straight line at a constant speed between consecutive control
+/// points.
+///
+/// ### Interpolation
+/// The curve passes through every control point.
+///
+/// ### Tangency
+/// Discontinuous at every control point, pointing towards the next one.
+///
+/// ### Continuity
+/// C0 continuous.
+///
+/// ### Usage
+///
+/// ```
+/// # use bevy_math::{*, prelude::*};
+/// let points = [
+///     vec2(-1.0, -20.0),
+///     vec2(3.0, 2.0),
+///     vec2(5.0, 3.0),
+///     vec2(9.0, 8.0),
+/// ];
+/// let linear = LinearSpline::new(points).to_curve();
+/// let positions: Vec<_> = linear.iter_positions(100).collect();
+/// ```
+pub struct LinearSpline<P: Point> {
+    points: Vec<P>,
+}
+impl<P: Point> LinearSpline<P> {
+    /// Build a new linear spline through the given points.
+    pub fn new(points: impl Into<Vec<P>>) -> Self {
+        Self {
+            points: points.into(),
+        }
+    }
+}
+impl<P: Point> CubicGenerator<P> for LinearSpline<P> {
+    #[inline]
+    fn to_curve(&self) -> CubicCurve<P> {
+        let segments = self
+            .points
+            .windows(2)
+            .map(|p| CubicSegment {
+                coeff: [p[0], p[1] - p[0], P::default(), P::default()],
+            })
+            .collect();
+
+        CubicCurve::from_segments(segments)
     }
 }
 
@@ -321,6 +481,136 @@ impl<P: Point> CubicSegment<P> {
         let [_, _, c, d] = self.coeff;
         c * 2.0 + d * 6.0 * t
     }
+
+    /// Split this segment at the parametric value `t` into two segments that together trace
+    /// the same curve, with the first one ending and the second one starting at `t`.
+    ///
+    /// Both halves are parameterized over `0..=1`.
+    ///
+    /// ```
+    /// # use bevy_math::{*, prelude::*};
+    /// let bezier = CubicBezier::new([[
+    ///     vec2(0.0, 0.0),
+    ///     vec2(1.0, 2.0),
+    ///     vec2(3.0, 2.0),
+    ///     vec2(4.0, 0.0),
+    /// ]])
+    /// .to_curve();
+    /// let (first, second) = bezier.segments()[0].split_at(0.25);
+    /// assert_eq!(first.position(1.0), bezier.position(0.25));
+    /// assert_eq!(second.position(0.0), bezier.position(0.25));
+    /// ```
+    #[inline]
+    pub fn split_at(&self, t: f32) -> (Self, Self) {
+        (self.reparameterize(0.0, t), self.reparameterize(t, 1.0))
+    }
+
+    /// The four control points of the cubic Bezier curve that traces the same curve as this
+    /// segment, in the same order as accepted by [`CubicBezier::new`].
+    #[inline]
+    pub fn to_bezier_control_points(&self) -> [P; 4] {
+        let [a, b, c, d] = self.coeff;
+        [
+            a,
+            a + b * (1.0 / 3.0),
+            a + b * (2.0 / 3.0) + c * (1.0 / 3.0),
+            a + b + c + d,
+        ]
+    }
+
+    /// The part of this segment between `t0` and `t1`, parameterized over `0..=1`.
+    #[inline]
+    fn reparameterize(&self, t0: f32, t1: f32) -> Self {
+        let [_, _, c, d] = self.coeff;
+        let width = t1 - t0;
+        // Taylor expansion of the polynomial around `t0`, scaled to the new parameter range.
+        CubicSegment {
+            coeff: [
+                self.position(t0),
+                self.velocity(t0) * width,
+                (c + d * 3.0 * t0) * width.powi(2),
+                d * width.powi(3),
+            ],
+        }
+    }
+}
+
+impl<P: NormedPoint> CubicSegment<P> {
+    /// Number of evenly spaced samples used to seed [`closest_point`](Self::closest_point).
+    const CLOSEST_POINT_SEEDS: usize = 8;
+
+    /// Maximum number of Newton iterations per seed in [`closest_point`](Self::closest_point).
+    const CLOSEST_POINT_NEWTON_ITERS: u8 = 6;
+
+    /// Find the point on this segment closest to `point`, returning its parametric value `t`
+    /// within `0..=1` and its position.
+    ///
+    /// The segment is sampled at several positions, which are then refined with Newton's method
+    /// to find local minima of the distance, so curves with multiple local minima are handled.
+    ///
+    /// ```
+    /// # use bevy_math::{*, prelude::*};
+    /// let bezier = CubicBezier::new([[
+    ///     vec2(-1.0, 0.0),
+    ///     vec2(-1.0, 1.0),
+    ///     vec2(1.0, 1.0),
+    ///     vec2(1.0, 0.0),
+    /// ]])
+    /// .to_curve();
+    /// let (t, position) = bezier.segments()[0].closest_point(vec2(0.0, 5.0));
+    /// assert!((t - 0.5).abs() < 1e-4);
+    /// assert!(position.distance(vec2(0.0, 0.75)) < 1e-4);
+    /// ```
+    pub fn closest_point(&self, point: P) -> (f32, P) {
+        let seeds = (0..=Self::CLOSEST_POINT_SEEDS)
+            .map(|i| i as f32 / Self::CLOSEST_POINT_SEEDS as f32)
+            .map(|t| self.refine_closest_point(point, t));
+        let t = nearest_by_key(seeds, |&t| (self.position(t) - point).length()).unwrap_or(0.0);
+        (t, self.position(t))
+    }
+
+    /// Newton's method on the derivative of the squared distance to `point`, starting at `t`.
+    #[inline]
+    fn refine_closest_point(&self, point: P, mut t: f32) -> f32 {
+        for _ in 0..Self::CLOSEST_POINT_NEWTON_ITERS {
+            let offset = self.position(t) - point;
+            let velocity = self.velocity(t);
+            let slope = offset.dot(velocity);
+            let curvature = velocity.dot(velocity) + offset.dot(self.acceleration(t));
+            // Only step towards minima, not maxima of the distance.
+            if curvature <= 0.0 {
+                break;
+            }
+            let next = (t - slope / curvature).clamp(0.0, 1.0);
+            if next == t {
+                break;
+            }
+            t = next;
+        }
+        t
+    }
+
+    /// Nodes and weights of the 5-point Gauss-Legendre quadrature on `-1..=1`.
+    const GAUSS_LEGENDRE: [(f32, f32); 5] = [
+        (0.0, 0.568_888_9),
+        (-0.538_469_3, 0.478_628_67),
+        (0.538_469_3, 0.478_628_67),
+        (-0.906_179_85, 0.236_926_88),
+        (0.906_179_85, 0.236_926_88),
+    ];
+
+    /// Approximate the arc length between the parametric values `t0` and `t1` by integrating the
+    /// speed with Gauss-Legendre quadrature.
+    #[inline]
+    fn arc_length_between(&self, t0: f32, t1: f32) -> f32 {
+        let half_width = (t1 - t0) / 2.0;
+        let midpoint = (t0 + t1) / 2.0;
+        let weighted_speeds: f32 = Self::GAUSS_LEGENDRE
+            .iter()
+            .map(|&(node, weight)| weight * self.velocity(midpoint + half_width * node).length())
+            .sum();
+        weighted_speeds * half_width
+    }
 }
 
 /// The `CubicSegment<Vec2>` can be used as a 2-dimensional easing curve for animation.
@@ -337,8 +627,11 @@ impl CubicSegment<Vec2> {
     /// example, the ubiquitous "ease-in-out" is defined as `(0.25, 0.1), (0.25, 1.0)`.
     pub fn new_bezier(p1: impl Into<Vec2>, p2: impl Into<Vec2>) -> Self {
         let (p0, p3) = (Vec2::ZERO, Vec2::ONE);
-        let bezier = CubicBezier::new([[p0, p1.into(), p2.into(), p3]]).to_curve();
-        bezier.segments[0].clone()
+        CubicCurve::coefficients(
+            [p0, p1.into(), p2.into(), p3],
+            1.0,
+            CubicBezier::<Vec2>::CHAR_MATRIX,
+        )
     }
 
     /// Maximum allowable error for iterative Bezier solve
@@ -431,12 +724,123 @@ impl CubicSegment<Vec2> {
 }
 
 /// A collection of [`CubicSegment`]s chained into a curve.
-#[derive(Clone, Debug, Default, PartialEq)]
+///
+/// ### Usage
+///
+/// Curves work with any [`Point`] type, including `f32` for one-dimensional animation tracks:
+///
+/// ```
+/// # use bevy_math::prelude::*;
+/// // The intensity of a light at three keyframes, one second apart.
+/// let keyframes = [0.0, 800.0, 400.0];
+/// let tangents = [0.0, 0.0, -400.0];
+/// let intensity = Hermite::new(keyframes, tangents).to_curve();
+///
+/// // Sample the track halfway between the second and third keyframe.
+/// let elapsed_seconds = 1.5;
+/// assert_eq!(intensity.position(elapsed_seconds), 650.0);
+/// ```
+#[derive(Clone, Debug)]
 pub struct CubicCurve<P: Point> {
     segments: Vec<CubicSegment<P>>,
+    /// The number of intervals each segment is split into in `arc_lengths`.
+    arc_length_resolution: usize,
+    /// The arc length from the start of the curve to the start of each interval, followed by the
+    /// length of the whole curve. This is computed when first needed, and reset whenever the
+    /// segments change.
+    arc_lengths: OnceLock<Vec<f32>>,
 }
 
 impl<P: Point> CubicCurve<P> {
+    /// The default number of intervals per segment used to approximate the arc length.
+    const DEFAULT_ARC_LENGTH_RESOLUTION: usize = 16;
+
+    /// Build a curve from its segments.
+    fn from_segments(segments: Vec<CubicSegment<P>>) -> Self {
+        Self {
+            segments,
+            ..Self::default()
+        }
+    }
+
+    /// Set the number of intervals each segment is split into when measuring the arc length of
+    /// the curve, which defaults to 16.
+    ///
+    /// Higher values make [`arc_length`](Self::arc_length) and
+    /// [`position_at_distance`](Self::position_at_distance) more accurate for curves with sharp
+    /// turns, at the cost of one `f32` of memory per interval. Values below 1 are treated as 1.
+    ///
+    /// ```
+    /// # use bevy_math::{*, prelude::*};
+    /// let bezier = CubicBezier::new([[
+    ///     vec2(0.0, 0.0),
+    ///     vec2(1.0, 0.0),
+    ///     vec2(2.0, 0.0),
+    ///     vec2(3.0, 0.0),
+    /// ]])
+    /// .to_curve()
+    /// .with_arc_length_resolution(32);
+    /// assert!((bezier.arc_length() - 3.0).abs() < 1e-5);
+    /// assert!(bezier.position_at_distance(1.0).distance(vec2(1.0, 0.0)) < 1e-5);
+    /// ```
+    pub fn with_arc_length_resolution(mut self, samples_per_segment: usize) -> Self {
+        self.arc_length_resolution = samples_per_segment.max(1);
+        self.arc_lengths.take();
+        self
+    }
+
+    /// Append a segment to the end of this curve, increasing the global `t` range by one.
+    ///
+    /// Continuity is not enforced: the segment is added as-is, even if it does not start where
+    /// the curve currently ends. Use [`is_c0_continuous`](Self::is_c0_continuous) to check.
+    pub fn push_segment(&mut self, segment: CubicSegment<P>) {
+        self.segments.push(segment);
+        self.arc_lengths.take();
+    }
+
+    /// Append all segments of `other` to the end of this curve.
+    ///
+    /// Like [`push_segment`](Self::push_segment), this does not enforce continuity between the
+    /// two curves.
+    pub fn extend(&mut self, other: CubicCurve<P>) {
+        self.segments.extend(other.segments);
+        self.arc_lengths.take();
+    }
+
+    /// A curve that traces this curve backwards, such that
+    /// `reversed.position(t) == self.position(self.segments().len() as f32 - t)`.
+    pub fn reversed(&self) -> Self {
+        let segments = self
+            .segments
+            .iter()
+            .rev()
+            .map(|segment| segment.reparameterize(1.0, 0.0))
+            .collect();
+
+        Self::from_segments(segments).with_arc_length_resolution(self.arc_length_resolution)
+    }
+
+    /// Split every segment of this curve into `segments_per_curve` segments of equal parametric
+    /// length, without changing the traced shape.
+    ///
+    /// Since the global `t` value depends on the number of segments, `position(t)` of the
+    /// returned curve corresponds to `position(t / segments_per_curve)` of this one.
+    /// Values of `segments_per_curve` below 1 are treated as 1.
+    pub fn subdivide(&self, segments_per_curve: usize) -> Self {
+        let subdivisions = segments_per_curve.max(1);
+        let step = 1.0 / subdivisions as f32;
+        let segments = self
+            .segments
+            .iter()
+            .flat_map(|segment| {
+                (0..subdivisions)
+                    .map(move |i| segment.reparameterize(i as f32 * step, (i + 1) as f32 * step))
+            })
+            .collect();
+
+        Self::from_segments(segments).with_arc_length_resolution(self.arc_length_resolution)
+    }
+
     /// Compute the position of a point on the cubic curve at the parametric value `t`.
     ///
     /// Note that `t` varies from `0..=(n_points - 3)`.
@@ -546,11 +950,244 @@ impl<P: Point> CubicCurve<P> {
     }
 }
 
+impl<P: NormedPoint> CubicCurve<P> {
+    /// Number of Newton iterations used to refine the parametric value in
+    /// [`position_at_distance`](Self::position_at_distance).
+    const ARC_LENGTH_NEWTON_ITERS: u8 = 4;
+
+    /// Returns `true` if every segment starts within `epsilon` of where the previous one ends,
+    /// i.e. the curve has no gaps.
+    pub fn is_c0_continuous(&self, epsilon: f32) -> bool {
+        self.segments
+            .windows(2)
+            .all(|pair| (pair[1].position(0.0) - pair[0].position(1.0)).length() <= epsilon)
+    }
+
+    /// The total length of the curve, as measured along the curve.
+    ///
+    /// This is approximated by numerical integration, see
+    /// [`with_arc_length_resolution`](Self::with_arc_length_resolution). The result is cached
+    /// until the segments of the curve change.
+    #[inline]
+    pub fn arc_length(&self) -> f32 {
+        self.arc_lengths().last().copied().unwrap_or(0.0)
+    }
+
+    /// Compute the position of the point that is `distance` away from the start of the curve,
+    /// as measured along the curve.
+    ///
+    /// Unlike [`position`](Self::position), sampling this at evenly spaced distances moves along
+    /// the curve at a constant speed. `distance` is clamped to `0..=self.arc_length()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the curve has no segments.
+    #[inline]
+    pub fn position_at_distance(&self, distance: f32) -> P {
+        let (segment, t) = self.segment_at_distance(distance);
+        segment.position(t)
+    }
+
+    /// Find the point on the curve closest to `point`, returning its global parametric value
+    /// `t` and its position.
+    ///
+    /// This uses [`CubicSegment::closest_point`] on each segment, and picks the closest result.
+    /// Returns `None` if the curve has no segments.
+    pub fn closest_point(&self, point: P) -> Option<(f32, P)> {
+        let candidates = self.segments.iter().enumerate().map(|(i, segment)| {
+            let (t, position) = segment.closest_point(point);
+            (i as f32 + t, position)
+        });
+        nearest_by_key(candidates, |(_, position)| (*position - point).length())
+    }
+
+    /// The cumulative arc length table, computed on first use.
+    fn arc_lengths(&self) -> &[f32] {
+        self.arc_lengths.get_or_init(|| {
+            let resolution = self.arc_length_resolution;
+            let step = 1.0 / resolution as f32;
+            let mut lengths = Vec::with_capacity(self.segments.len() * resolution + 1);
+            let mut length = 0.0;
+            lengths.push(length);
+            for segment in &self.segments {
+                for i in 0..resolution {
+                    length += segment.arc_length_between(i as f32 * step, (i + 1) as f32 * step);
+                    lengths.push(length);
+                }
+            }
+            lengths
+        })
+    }
+
+    /// Returns the [`CubicSegment`] and local `t` value at the given arc length `distance`.
+    fn segment_at_distance(&self, distance: f32) -> (&CubicSegment<P>, f32) {
+        let resolution = self.arc_length_resolution;
+        let lengths = self.arc_lengths();
+        let distance = distance.clamp(0.0, self.arc_length());
+
+        // Find the interval containing `distance` in the cumulative length table.
+        let last_interval = lengths.len().saturating_sub(2);
+        let interval = lengths
+            .partition_point(|&length| length <= distance)
+            .saturating_sub(1)
+            .min(last_interval);
+        let segment = &self.segments[interval / resolution];
+        let step = 1.0 / resolution as f32;
+        let t_start = (interval % resolution) as f32 * step;
+        let t_end = t_start + step;
+
+        // Interpolate linearly within the interval, then refine with Newton's method.
+        let target = distance - lengths[interval];
+        let interval_length = lengths[interval + 1] - lengths[interval];
+        if interval_length <= 0.0 {
+            return (segment, t_start);
+        }
+        let mut t = t_start + step * target / interval_length;
+        for _ in 0..Self::ARC_LENGTH_NEWTON_ITERS {
+            let speed = segment.velocity(t).length();
+            if speed <= f32::EPSILON {
+                break;
+            }
+            let error = segment.arc_length_between(t_start, t) - target;
+            t = (t - error / speed).clamp(t_start, t_end);
+        }
+        (segment, t)
+    }
+}
+
+impl CubicCurve<Vec2> {
+    /// The signed curvature of the curve at the parametric value `t`, which is the reciprocal
+    /// of the radius of the circle that best approximates the curve at that point.
+    ///
+    /// The curvature is positive where the curve turns counterclockwise, negative where it
+    /// turns clockwise, and zero where it is straight or has no velocity.
+    pub fn curvature(&self, t: f32) -> f32 {
+        let (segment, t) = self.segment(t);
+        let velocity = segment.velocity(t);
+        let speed = velocity.length();
+        if speed <= f32::EPSILON {
+            return 0.0;
+        }
+        velocity.perp_dot(segment.acceleration(t)) / speed.powi(3)
+    }
+}
+
+impl CubicCurve<Vec3> {
+    /// The curvature of the curve at the parametric value `t`, which is the reciprocal of the
+    /// radius of the circle that best approximates the curve at that point.
+    ///
+    /// The curvature is zero where the curve is straight or has no velocity.
+    pub fn curvature(&self, t: f32) -> f32 {
+        let (segment, t) = self.segment(t);
+        let velocity = segment.velocity(t);
+        let speed = velocity.length();
+        if speed <= f32::EPSILON {
+            return 0.0;
+        }
+        velocity.cross(segment.acceleration(t)).length() / speed.powi(3)
+    }
+
+    /// Compute rotation-minimizing frames along the curve, split into `subdivisions` like
+    /// [`iter_positions`](Self::iter_positions), returning `subdivisions + 1` frames.
+    ///
+    /// Each frame is a position on the curve and a rotation whose forward direction
+    /// ([`Vec3::NEG_Z`]) follows the tangent of the curve, and whose up direction ([`Vec3::Y`])
+    /// twists as little as possible from one frame to the next. This makes them well suited for
+    /// sweeping geometry such as roads or tubes along the curve, and for orienting cameras.
+    ///
+    /// The first up direction is as close to [`Vec3::Y`] as possible. The frames are computed
+    /// with the double reflection method, so more subdivisions give more accurate frames.
+    pub fn rotation_minimizing_frames(&self, subdivisions: usize) -> Vec<(Vec3, Quat)> {
+        let mut frames = Vec::with_capacity(subdivisions + 1);
+        if self.segments.is_empty() {
+            return frames;
+        }
+        let mut samples = self
+            .iter_uniformly(subdivisions)
+            .map(|t| (self.position(t), self.velocity(t)));
+
+        let Some((mut position, velocity)) = samples.next() else {
+            return frames;
+        };
+        let mut tangent = velocity.try_normalize().unwrap_or(Vec3::NEG_Z);
+        let reference = if tangent.y.abs() < 0.99 {
+            Vec3::Y
+        } else {
+            Vec3::X
+        };
+        let mut normal = (reference - tangent * reference.dot(tangent)).normalize();
+        frames.push((position, Self::frame_rotation(tangent, normal)));
+
+        for (next_position, next_velocity) in samples {
+            let next_tangent = next_velocity.try_normalize().unwrap_or(tangent);
+
+            // Reflect the frame across the plane bisecting the two positions, then across the
+            // plane that maps the reflected tangent onto the next tangent.
+            let step = next_position - position;
+            let step_squared = step.length_squared();
+            let (reflected_normal, reflected_tangent) = if step_squared > 0.0 {
+                (
+                    normal - step * (2.0 / step_squared * step.dot(normal)),
+                    tangent - step * (2.0 / step_squared * step.dot(tangent)),
+                )
+            } else {
+                (normal, tangent)
+            };
+            let correction = next_tangent - reflected_tangent;
+            let correction_squared = correction.length_squared();
+            let next_normal = if correction_squared > 0.0 {
+                reflected_normal
+                    - correction * (2.0 / correction_squared * correction.dot(reflected_normal))
+            } else {
+                reflected_normal
+            };
+
+            // Keep the frame orthonormal as errors accumulate.
+            normal = (next_normal - next_tangent * next_normal.dot(next_tangent))
+                .try_normalize()
+                .unwrap_or(normal);
+            tangent = next_tangent;
+            position = next_position;
+            frames.push((position, Self::frame_rotation(tangent, normal)));
+        }
+        frames
+    }
+
+    /// The rotation that maps [`Vec3::NEG_Z`] to `tangent` and [`Vec3::Y`] to `normal`.
+    #[inline]
+    fn frame_rotation(tangent: Vec3, normal: Vec3) -> Quat {
+        Quat::from_mat3(&Mat3::from_cols(tangent.cross(normal), normal, -tangent))
+    }
+}
+
+impl<P: Point> Default for CubicCurve<P> {
+    fn default() -> Self {
+        Self {
+            segments: Vec::new(),
+            arc_length_resolution: Self::DEFAULT_ARC_LENGTH_RESOLUTION,
+            arc_lengths: OnceLock::new(),
+        }
+    }
+}
+
+/// Curves are equal if they have the same segments, regardless of how their arc length is
+/// measured.
+impl<P: Point> PartialEq for CubicCurve<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.segments == other.segments
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use glam::{vec2, Vec2};
+    use std::f32::consts::TAU;
+
+    use glam::{vec2, vec3, vec4, Vec2, Vec3};
 
-    use crate::cubic_splines::{CubicBezier, CubicGenerator, CubicSegment};
+    use crate::cubic_splines::{
+        BSpline, CardinalSpline, CubicBezier, CubicCurve, CubicGenerator, CubicSegment, Hermite,
+        LinearSpline, QuadraticBezier,
+    };
 
     /// How close two floats can be and still be considered equal
     const FLOAT_EQ: f32 = 1e-5;
@@ -613,4 +1250,554 @@ mod tests {
         assert!(bezier.ease(0.5) < -0.5);
         assert_eq!(bezier.ease(1.0), 1.0);
     }
+
+    /// Build an `f32` Hermite track from keyframes, and compare it against the hand-computed
+    /// Hermite basis functions.
+    #[test]
+    fn hermite_f32() {
+        const N_SAMPLES: usize = 100;
+        let keyframes = [0.0, 5.0, -2.0, 3.0];
+        let tangents = [1.0, 0.0, -4.0, 2.0];
+        let hermite = Hermite::new(keyframes, tangents).to_curve();
+        assert_eq!(hermite.segments().len(), 3);
+
+        for segment in 0..3 {
+            let (p0, v0) = (keyframes[segment], tangents[segment]);
+            let (p1, v1) = (keyframes[segment + 1], tangents[segment + 1]);
+            for i in 0..N_SAMPLES {
+                let t = i as f32 / N_SAMPLES as f32;
+                let manual = (2.0 * t.powi(3) - 3.0 * t.powi(2) + 1.0) * p0
+                    + (t.powi(3) - 2.0 * t.powi(2) + t) * v0
+                    + (-2.0 * t.powi(3) + 3.0 * t.powi(2)) * p1
+                    + (t.powi(3) - t.powi(2)) * v1;
+                let sampled = hermite.position(segment as f32 + t);
+                assert!((sampled - manual).abs() <= FLOAT_EQ);
+            }
+        }
+
+        let positions: Vec<f32> = hermite.iter_positions(3).collect();
+        assert_eq!(positions, [0.0, 5.0, -2.0, 3.0]);
+    }
+
+    /// Every generator supports `f32`, giving the same result as one axis of a 2D curve.
+    #[test]
+    fn generators_f32() {
+        let points = [
+            vec2(-1.0, -20.0),
+            vec2(3.0, 2.0),
+            vec2(5.0, 3.0),
+            vec2(9.0, 8.0),
+        ];
+        let xs = points.map(|p| p.x);
+
+        let curves_2d = [
+            CubicBezier::new([points]).to_curve(),
+            Hermite::new(points, points).to_curve(),
+            CardinalSpline::new(0.3, points).to_curve(),
+            BSpline::new(points).to_curve(),
+        ];
+        let curves_f32 = [
+            CubicBezier::new([xs]).to_curve(),
+            Hermite::new(xs, xs).to_curve(),
+            CardinalSpline::new(0.3, xs).to_curve(),
+            BSpline::new(xs).to_curve(),
+        ];
+        for (curve_2d, curve_f32) in curves_2d.iter().zip(&curves_f32) {
+            let positions_2d = curve_2d.iter_positions(20);
+            let velocities_2d = curve_2d.iter_velocities(20);
+            let positions_f32 = curve_f32.iter_positions(20);
+            let velocities_f32 = curve_f32.iter_velocities(20);
+            assert!(positions_2d
+                .zip(positions_f32)
+                .all(|(a, b)| (a.x - b).abs() <= FLOAT_EQ));
+            assert!(velocities_2d
+                .zip(velocities_f32)
+                .all(|(a, b)| (a.x - b).abs() <= FLOAT_EQ));
+        }
+    }
+
+    /// Four-component values, such as colors, can be interpolated too.
+    #[test]
+    fn cubic_vec4() {
+        let from = vec4(1.0, 0.0, 0.0, 1.0);
+        let to = vec4(0.0, 0.0, 1.0, 0.5);
+        let bezier = CubicBezier::new([[from, from, to, to]]).to_curve();
+        assert_eq!(bezier.position(0.0), from);
+        assert_eq!(bezier.position(1.0), to);
+        assert!((bezier.position(0.5) - (from + to) / 2.0).length() <= FLOAT_EQ);
+    }
+
+    /// A straight line with non-uniform speed must report its exact length, and be sampled
+    /// uniformly by distance.
+    #[test]
+    fn arc_length_straight_line() {
+        let end = vec3(3.0, -4.0, 12.0);
+        let bezier = CubicBezier::new([[end * 0.0, end * 0.05, end * 0.1, end]]).to_curve();
+        assert!((bezier.arc_length() - 13.0).abs() <= FLOAT_EQ * 13.0);
+
+        for i in 0..=10 {
+            let fraction = i as f32 / 10.0;
+            let position = bezier.position_at_distance(fraction * 13.0);
+            assert!(position.distance(end * fraction) <= 1e-4);
+        }
+
+        // Distances outside of the curve are clamped.
+        assert_eq!(bezier.position_at_distance(-1.0), end * 0.0);
+        assert!(bezier.position_at_distance(100.0).distance(end) <= FLOAT_EQ);
+    }
+
+    /// Four cubic Bezier arcs approximating a circle should have nearly its circumference.
+    #[test]
+    fn arc_length_circle() {
+        const K: f32 = 0.552_284_8;
+        let radius = 5.0;
+        let quarter = |start: Vec2| {
+            let end = start.perp();
+            [start, start + end * K, end + start * K, end].map(|p| p * radius)
+        };
+        let circle = CubicBezier::new([
+            quarter(Vec2::X),
+            quarter(Vec2::Y),
+            quarter(Vec2::NEG_X),
+            quarter(Vec2::NEG_Y),
+        ])
+        .to_curve();
+
+        let circumference = TAU * radius;
+        assert!((circle.arc_length() - circumference).abs() <= circumference * 0.001);
+
+        // A coarser resolution trades accuracy for memory, but stays close for smooth curves.
+        let coarse = circle.clone().with_arc_length_resolution(1);
+        assert!((coarse.arc_length() - circumference).abs() <= circumference * 0.001);
+        // The resolution does not affect equality.
+        assert_eq!(coarse, circle);
+
+        // Points at a quarter of the length apart are a quarter turn apart.
+        let length = circle.arc_length();
+        for i in 0..4 {
+            let position = circle.position_at_distance(i as f32 * length / 4.0);
+            let expected = [Vec2::X, Vec2::Y, Vec2::NEG_X, Vec2::NEG_Y][i] * radius;
+            assert!(position.distance(expected) <= 1e-3);
+        }
+    }
+
+    /// Sampling a curve with sharp turns at even distances moves at a constant speed.
+    #[test]
+    fn position_at_distance_constant_speed() {
+        let points = [[
+            vec2(-6.0, 2.0),
+            vec2(12.0, 8.0),
+            vec2(-12.0, 8.0),
+            vec2(6.0, 2.0),
+        ]];
+        let bezier = CubicBezier::new(points).to_curve();
+        let length = bezier.arc_length();
+
+        const N_SAMPLES: usize = 1000;
+        let step = length / N_SAMPLES as f32;
+        for i in 0..N_SAMPLES {
+            let a = bezier.position_at_distance(i as f32 * step);
+            let b = bezier.position_at_distance((i + 1) as f32 * step);
+            // Chords are slightly shorter than arcs, so allow for some error.
+            assert!((a.distance(b) - step).abs() <= step * 0.01);
+        }
+    }
+
+    /// `f32` curves measure the total distance travelled along the number line.
+    #[test]
+    fn arc_length_f32() {
+        let hermite = Hermite::new([0.0, 4.0, 1.0], [0.0, 0.0, 0.0]).to_curve();
+        assert!((hermite.arc_length() - 7.0).abs() <= 1e-4);
+        assert!((hermite.position_at_distance(5.0) - 3.0).abs() <= 1e-4);
+    }
+
+    /// Splitting a segment must not change the traced curve.
+    #[test]
+    fn split_at() {
+        const N_SAMPLES: usize = 1000;
+        let bezier = CubicBezier::new([[
+            vec3(-1.0, -20.0, 0.0),
+            vec3(3.0, 2.0, 5.0),
+            vec3(5.0, 3.0, -2.0),
+            vec3(9.0, 8.0, 1.0),
+        ]])
+        .to_curve();
+        let segment = &bezier.segments()[0];
+
+        for split in [0.0, 0.3, 0.5, 0.99] {
+            let (first, second) = segment.split_at(split);
+            for i in 0..=N_SAMPLES {
+                let t = i as f32 / N_SAMPLES as f32;
+                let expected = segment.position(t);
+                let actual = if t < split {
+                    first.position(t / split)
+                } else {
+                    second.position((t - split) / (1.0 - split))
+                };
+                assert!(actual.distance(expected) <= 1e-4);
+            }
+        }
+    }
+
+    /// Splitting in half gives the control points from de Casteljau's algorithm.
+    #[test]
+    fn split_at_bezier_control_points() {
+        let points = [
+            vec2(0.0, 0.0),
+            vec2(0.0, 4.0),
+            vec2(4.0, 4.0),
+            vec2(4.0, 0.0),
+        ];
+        let bezier = CubicBezier::new([points]).to_curve();
+        assert_eq!(bezier.segments()[0].to_bezier_control_points(), points);
+
+        let (first, second) = bezier.segments()[0].split_at(0.5);
+        assert_eq!(
+            first.to_bezier_control_points(),
+            [
+                vec2(0.0, 0.0),
+                vec2(0.0, 2.0),
+                vec2(1.0, 3.0),
+                vec2(2.0, 3.0)
+            ]
+        );
+        assert_eq!(
+            second.to_bezier_control_points(),
+            [
+                vec2(2.0, 3.0),
+                vec2(3.0, 3.0),
+                vec2(4.0, 2.0),
+                vec2(4.0, 0.0)
+            ]
+        );
+    }
+
+    /// Subdividing a curve keeps its shape and length.
+    #[test]
+    fn subdivide() {
+        const N_SAMPLES: usize = 1000;
+        let points = [
+            vec2(-1.0, -20.0),
+            vec2(3.0, 2.0),
+            vec2(5.0, 3.0),
+            vec2(9.0, 8.0),
+            vec2(12.0, -4.0),
+        ];
+        let curve = CardinalSpline::new_catmull_rom(points).to_curve();
+        let subdivided = curve.subdivide(3);
+        assert_eq!(subdivided.segments().len(), 3 * curve.segments().len());
+
+        let segment_count = curve.segments().len() as f32;
+        for i in 0..=N_SAMPLES {
+            let t = segment_count * i as f32 / N_SAMPLES as f32;
+            assert!(subdivided.position(3.0 * t).distance(curve.position(t)) <= 1e-4);
+        }
+        assert!((subdivided.arc_length() - curve.arc_length()).abs() <= 1e-3);
+
+        assert_eq!(curve.subdivide(0), curve.subdivide(1));
+        assert_eq!(curve.subdivide(1).segments(), curve.segments());
+    }
+
+    /// Points on a symmetric arc project onto its apex, and points on the curve onto themselves.
+    #[test]
+    fn closest_point_segment() {
+        let bezier = CubicBezier::new([[
+            vec2(-1.0, 0.0),
+            vec2(-1.0, 1.0),
+            vec2(1.0, 1.0),
+            vec2(1.0, 0.0),
+        ]])
+        .to_curve();
+        let segment = &bezier.segments()[0];
+
+        for height in [0.0, 0.5, 2.0, 100.0] {
+            let (t, position) = segment.closest_point(vec2(0.0, height));
+            assert!((t - 0.5).abs() <= 1e-4);
+            assert!(position.distance(vec2(0.0, 0.75)) <= 1e-4);
+        }
+
+        for i in 0..=20 {
+            let t = i as f32 / 20.0;
+            let on_curve = segment.position(t);
+            let (closest_t, position) = segment.closest_point(on_curve);
+            assert!((closest_t - t).abs() <= 1e-3);
+            assert!(position.distance(on_curve) <= 1e-5);
+        }
+
+        // Points beyond the ends project onto the endpoints.
+        assert_eq!(
+            segment.closest_point(vec2(-5.0, -5.0)),
+            (0.0, vec2(-1.0, 0.0))
+        );
+        assert_eq!(
+            segment.closest_point(vec2(5.0, -5.0)),
+            (1.0, vec2(1.0, 0.0))
+        );
+    }
+
+    /// The global minimum is found on curves with several local minima.
+    #[test]
+    fn closest_point_matches_brute_force() {
+        const N_SAMPLES: usize = 10_000;
+        let curve = CardinalSpline::new_catmull_rom([
+            vec2(-4.0, 0.0),
+            vec2(-3.0, 3.0),
+            vec2(-1.0, -3.0),
+            vec2(1.0, 3.0),
+            vec2(3.0, -3.0),
+            vec2(4.0, 0.0),
+        ])
+        .to_curve();
+        let segment_count = curve.segments().len() as f32;
+
+        for x in -5..=5 {
+            for y in -4..=4 {
+                let point = vec2(x as f32 * 0.9, y as f32);
+                let brute_force = (0..=N_SAMPLES)
+                    .map(|i| curve.position(segment_count * i as f32 / N_SAMPLES as f32))
+                    .map(|position| position.distance(point))
+                    .fold(f32::INFINITY, f32::min);
+
+                let (t, position) = curve.closest_point(point).unwrap();
+                assert!(curve.position(t).distance(position) <= 1e-4);
+                assert!(position.distance(point) <= brute_force + 1e-4);
+            }
+        }
+    }
+
+    /// Empty curves, such as splines with too few points, have no closest point.
+    #[test]
+    fn closest_point_empty_curve() {
+        assert_eq!(
+            CubicCurve::<Vec2>::default().closest_point(Vec2::ZERO),
+            None
+        );
+        let spline = CardinalSpline::new_catmull_rom([vec2(0.0, 0.0), vec2(1.0, 0.0)]).to_curve();
+        assert!(spline.segments().is_empty());
+        assert_eq!(spline.closest_point(Vec2::ONE), None);
+    }
+
+    /// A reversed curve traces the same points backwards.
+    #[test]
+    fn reversed() {
+        const N_SAMPLES: usize = 1000;
+        let curve = BSpline::new([
+            vec3(-1.0, -20.0, 0.0),
+            vec3(3.0, 2.0, 1.0),
+            vec3(5.0, 3.0, 0.0),
+            vec3(9.0, 8.0, -4.0),
+            vec3(12.0, -4.0, 2.0),
+            vec3(0.0, 0.0, 0.0),
+        ])
+        .to_curve();
+        let reversed = curve.reversed();
+        let end = curve.segments().len() as f32;
+        assert_eq!(reversed.segments().len(), curve.segments().len());
+
+        for i in 0..=N_SAMPLES {
+            let t = end * i as f32 / N_SAMPLES as f32;
+            assert!(reversed.position(t).distance(curve.position(end - t)) <= 1e-4);
+            assert!(reversed.velocity(t).distance(-curve.velocity(end - t)) <= 1e-3);
+        }
+        assert!((reversed.arc_length() - curve.arc_length()).abs() <= 1e-3);
+        assert!(
+            reversed
+                .reversed()
+                .position(0.7)
+                .distance(curve.position(0.7))
+                <= 1e-4
+        );
+    }
+
+    /// Curves can be built up from segments and other curves, without enforcing continuity.
+    #[test]
+    fn push_and_extend() {
+        let first = CubicBezier::new([[
+            vec2(0.0, 0.0),
+            vec2(0.0, 1.0),
+            vec2(1.0, 1.0),
+            vec2(1.0, 0.0),
+        ]])
+        .to_curve();
+        let second = CardinalSpline::new_catmull_rom([
+            vec2(0.0, 0.0),
+            vec2(1.0, 0.0),
+            vec2(2.0, 1.0),
+            vec2(3.0, 0.0),
+            vec2(4.0, 2.0),
+        ])
+        .to_curve();
+
+        let mut curve = CubicCurve::default();
+        assert_eq!(curve.arc_length(), 0.0);
+        curve.extend(first.clone());
+        assert_eq!(curve, first);
+
+        curve.extend(second.clone());
+        assert_eq!(curve.segments().len(), 3);
+        assert!((curve.arc_length() - first.arc_length() - second.arc_length()).abs() <= 1e-4);
+        assert_eq!(curve.position(1.5), second.position(0.5));
+        // The first curve ends at (1, 0), where the second one starts.
+        assert!(curve.is_c0_continuous(1e-6));
+
+        // Changing the segments updates the measured length.
+        let length = curve.arc_length();
+        curve.push_segment(first.segments()[0].clone());
+        assert_eq!(curve.segments().len(), 4);
+        assert!((curve.arc_length() - length - first.arc_length()).abs() <= 1e-4);
+        assert_eq!(curve.position(3.25), first.position(0.25));
+        // The pushed segment starts at (0, 0), far from the end of the curve at (3, 0).
+        assert!(!curve.is_c0_continuous(1.0));
+        assert!(curve.is_c0_continuous(3.5));
+    }
+
+    /// Builds a circle of the given `radius` in the XY plane from four cubic Bezier arcs,
+    /// going counterclockwise.
+    fn bezier_circle(radius: f32) -> CubicCurve<Vec2> {
+        const K: f32 = 0.552_284_8;
+        let quarter = |start: Vec2| {
+            let end = start.perp();
+            [start, start + end * K, end + start * K, end].map(|p| p * radius)
+        };
+        CubicBezier::new([
+            quarter(Vec2::X),
+            quarter(Vec2::Y),
+            quarter(Vec2::NEG_X),
+            quarter(Vec2::NEG_Y),
+        ])
+        .to_curve()
+    }
+
+    /// A circle-like curve has a nearly constant curvature of `1 / radius`.
+    #[test]
+    fn curvature_circle() {
+        let radius = 4.0;
+        let circle = bezier_circle(radius);
+        let reversed = circle.reversed();
+        let circle_3d = CubicBezier::new(
+            circle
+                .segments()
+                .iter()
+                .map(|segment| {
+                    segment
+                        .to_bezier_control_points()
+                        .map(|p| vec3(p.x, 2.0, p.y))
+                })
+                .collect::<Vec<_>>(),
+        )
+        .to_curve();
+
+        // The curvature of the Bezier approximation deviates from a true circle by up to ~2%.
+        let tolerance = 0.03 / radius;
+        for i in 0..=100 {
+            let t = 4.0 * i as f32 / 100.0;
+            assert!((circle.curvature(t) - 1.0 / radius).abs() <= tolerance);
+            // Going clockwise flips the sign in 2D.
+            assert!((reversed.curvature(t) + 1.0 / radius).abs() <= tolerance);
+            assert!((circle_3d.curvature(t) - 1.0 / radius).abs() <= tolerance);
+        }
+
+        let line = CubicBezier::new([[Vec3::ZERO, Vec3::X, Vec3::X * 2.0, Vec3::X * 3.0]]);
+        assert_eq!(line.to_curve().curvature(0.5), 0.0);
+    }
+
+    /// Frames follow the tangent and only twist by small amounts between samples.
+    #[test]
+    fn rotation_minimizing_frames_helix() {
+        let helix = CardinalSpline::new_catmull_rom(
+            (0..40)
+                .map(|i| {
+                    let angle = i as f32 * 0.4;
+                    vec3(angle.cos() * 3.0, i as f32 * 0.25, angle.sin() * 3.0)
+                })
+                .collect::<Vec<_>>(),
+        )
+        .to_curve();
+
+        let subdivisions = 500;
+        let frames = helix.rotation_minimizing_frames(subdivisions);
+        assert_eq!(frames.len(), subdivisions + 1);
+
+        let end = helix.segments().len() as f32;
+        for (i, (position, rotation)) in frames.iter().enumerate() {
+            let t = end * i as f32 / subdivisions as f32;
+            assert!(position.distance(helix.position(t)) <= 1e-5);
+            let forward = *rotation * Vec3::NEG_Z;
+            assert!(forward.distance(helix.velocity(t).normalize()) <= 1e-4);
+            assert!(rotation.is_normalized());
+        }
+
+        for pair in frames.windows(2) {
+            let (rotation, next) = (pair[0].1, pair[1].1);
+            assert!(rotation.angle_between(next) < 0.1);
+            // No sign flips of the up direction.
+            assert!((rotation * Vec3::Y).dot(next * Vec3::Y) > 0.99);
+        }
+    }
+
+    /// The up direction of a curve in a plane stays perpendicular to that plane.
+    #[test]
+    fn rotation_minimizing_frames_planar() {
+        let curve = CardinalSpline::new_catmull_rom([
+            vec3(0.0, 1.0, 0.0),
+            vec3(2.0, 1.0, 0.0),
+            vec3(4.0, 1.0, 3.0),
+            vec3(2.0, 1.0, 6.0),
+            vec3(-1.0, 1.0, 4.0),
+            vec3(0.0, 1.0, 0.0),
+        ])
+        .to_curve();
+        for (_, rotation) in curve.rotation_minimizing_frames(200) {
+            assert!((rotation * Vec3::Y).distance(Vec3::Y) <= 1e-4);
+        }
+
+        assert!(CubicCurve::<Vec3>::default()
+            .rotation_minimizing_frames(10)
+            .is_empty());
+    }
+
+    /// A linear spline moves in straight lines at a constant speed.
+    #[test]
+    fn linear_spline() {
+        let points = [
+            vec2(-1.0, -20.0),
+            vec2(3.0, 2.0),
+            vec2(5.0, 3.0),
+            vec2(9.0, 8.0),
+        ];
+        let linear = LinearSpline::new(points).to_curve();
+        assert_eq!(linear.segments().len(), 3);
+
+        for (i, pair) in points.windows(2).enumerate() {
+            assert_eq!(linear.position(i as f32), pair[0]);
+            assert_eq!(linear.position(i as f32 + 0.5), (pair[0] + pair[1]) / 2.0);
+            assert_eq!(linear.velocity(i as f32 + 0.25), pair[1] - pair[0]);
+            assert_eq!(linear.acceleration(i as f32 + 0.75), Vec2::ZERO);
+        }
+        assert_eq!(linear.position(3.0), points[3]);
+
+        let length: f32 = points.windows(2).map(|p| p[0].distance(p[1])).sum();
+        assert!((linear.arc_length() - length).abs() <= 1e-4);
+    }
+
+    /// Degree elevation of a quadratic Bezier is lossless.
+    #[test]
+    fn quadratic_bezier() {
+        const N_SAMPLES: usize = 100;
+        let points = [
+            [vec2(0.0, 0.0), vec2(1.0, 3.0), vec2(4.0, 1.0)],
+            [vec2(4.0, 1.0), vec2(7.0, -1.0), vec2(6.0, -4.0)],
+        ];
+        let bezier = QuadraticBezier::new(points).to_curve();
+        assert_eq!(bezier.segments().len(), 2);
+
+        for (segment, [p0, p1, p2]) in points.into_iter().enumerate() {
+            for i in 0..=N_SAMPLES {
+                let t = i as f32 / N_SAMPLES as f32;
+                let expected = p0 * (1.0 - t).powi(2) + p1 * 2.0 * t * (1.0 - t) + p2 * t.powi(2);
+                let actual = bezier.position(segment as f32 + t);
+                assert!(actual.distance(expected) <= FLOAT_EQ);
+            }
+        }
+    }
 }
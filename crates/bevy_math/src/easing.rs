@@ -0,0 +1,244 @@
+//! Common easing functions for animations.
+
+use std::f32::consts::PI;
+
+use crate::{cubic_splines::CubicSegment, Vec2};
+
+/// Common easing functions that map the progress of an animation to an eased value.
+///
+/// All functions start at `0` and end at `1`, but some may leave the `0..=1` range in between,
+/// such as the `Back` functions which overshoot slightly. For custom curves, use
+/// [`CubicSegment::new_bezier`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use bevy_math::EaseFunction;
+/// let progress = 0.25;
+/// let eased = EaseFunction::QuadIn.sample(progress);
+/// assert_eq!(eased, 0.0625);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EaseFunction {
+    /// Constant speed, `f(t) = t`.
+    Linear,
+
+    /// The CSS `ease` timing function, `cubic-bezier(0.25, 0.1, 0.25, 1.0)`.
+    Ease,
+    /// The CSS `ease-in` timing function, `cubic-bezier(0.42, 0.0, 1.0, 1.0)`.
+    EaseIn,
+    /// The CSS `ease-out` timing function, `cubic-bezier(0.0, 0.0, 0.58, 1.0)`.
+    EaseOut,
+    /// The CSS `ease-in-out` timing function, `cubic-bezier(0.42, 0.0, 0.58, 1.0)`.
+    EaseInOut,
+
+    /// Starts slow and accelerates, following a quarter of a sine wave.
+    SineIn,
+    /// Starts fast and decelerates, following a quarter of a sine wave.
+    SineOut,
+    /// Accelerates and then decelerates, following half of a sine wave.
+    SineInOut,
+
+    /// Starts slow and accelerates, `f(t) = t²`.
+    QuadIn,
+    /// Starts fast and decelerates, `f(t) = 1 - (1 - t)²`.
+    QuadOut,
+    /// Accelerates and then decelerates quadratically.
+    QuadInOut,
+
+    /// Starts slow and accelerates, `f(t) = t³`.
+    CubicIn,
+    /// Starts fast and decelerates, `f(t) = 1 - (1 - t)³`.
+    CubicOut,
+    /// Accelerates and then decelerates cubically.
+    CubicInOut,
+
+    /// Moves slightly backwards before accelerating towards the end.
+    BackIn,
+    /// Overshoots the end slightly before settling.
+    BackOut,
+    /// Moves slightly backwards at the start and overshoots slightly at the end.
+    BackInOut,
+}
+
+impl EaseFunction {
+    /// Overshoot of the `Back` functions, which makes them go about 10% past the end.
+    const BACK_OVERSHOOT: f32 = 1.70158;
+
+    /// Overshoot of [`EaseFunction::BackInOut`] for each half of the animation.
+    const BACK_IN_OUT_OVERSHOOT: f32 = Self::BACK_OVERSHOOT * 1.525;
+
+    /// Sample the easing function at the animation progress `t`, which is clamped to `0..=1`.
+    ///
+    /// The result is exactly `0` for `t <= 0` and exactly `1` for `t >= 1`, and monotonically
+    /// increasing for all functions except the `Back` ones.
+    pub fn sample(self, t: f32) -> f32 {
+        if t <= 0.0 {
+            return 0.0;
+        }
+        if t >= 1.0 {
+            return 1.0;
+        }
+
+        match self {
+            EaseFunction::Linear => t,
+
+            EaseFunction::Ease => Self::css_bezier((0.25, 0.1), (0.25, 1.0)).ease(t),
+            EaseFunction::EaseIn => Self::css_bezier((0.42, 0.0), (1.0, 1.0)).ease(t),
+            EaseFunction::EaseOut => Self::css_bezier((0.0, 0.0), (0.58, 1.0)).ease(t),
+            EaseFunction::EaseInOut => Self::css_bezier((0.42, 0.0), (0.58, 1.0)).ease(t),
+
+            EaseFunction::SineIn => 1.0 - (t * PI / 2.0).cos(),
+            EaseFunction::SineOut => (t * PI / 2.0).sin(),
+            EaseFunction::SineInOut => (1.0 - (t * PI).cos()) / 2.0,
+
+            EaseFunction::QuadIn => t * t,
+            EaseFunction::QuadOut => 1.0 - (1.0 - t).powi(2),
+            EaseFunction::QuadInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (2.0 - 2.0 * t).powi(2) / 2.0
+                }
+            }
+
+            EaseFunction::CubicIn => t.powi(3),
+            EaseFunction::CubicOut => 1.0 - (1.0 - t).powi(3),
+            EaseFunction::CubicInOut => {
+                if t < 0.5 {
+                    4.0 * t.powi(3)
+                } else {
+                    1.0 - (2.0 - 2.0 * t).powi(3) / 2.0
+                }
+            }
+
+            EaseFunction::BackIn => Self::back_in(t, Self::BACK_OVERSHOOT),
+            EaseFunction::BackOut => 1.0 - Self::back_in(1.0 - t, Self::BACK_OVERSHOOT),
+            EaseFunction::BackInOut => {
+                if t < 0.5 {
+                    Self::back_in(2.0 * t, Self::BACK_IN_OUT_OVERSHOOT) / 2.0
+                } else {
+                    1.0 - Self::back_in(2.0 - 2.0 * t, Self::BACK_IN_OUT_OVERSHOOT) / 2.0
+                }
+            }
+        }
+    }
+
+    /// The easing curve of a CSS `cubic-bezier()` timing function.
+    #[inline]
+    fn css_bezier(p1: (f32, f32), p2: (f32, f32)) -> CubicSegment<Vec2> {
+        CubicSegment::new_bezier(p1, p2)
+    }
+
+    /// Ease in while first moving backwards by an amount controlled by `overshoot`.
+    #[inline]
+    fn back_in(t: f32, overshoot: f32) -> f32 {
+        t * t * ((overshoot + 1.0) * t - overshoot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [EaseFunction; 17] = [
+        EaseFunction::Linear,
+        EaseFunction::Ease,
+        EaseFunction::EaseIn,
+        EaseFunction::EaseOut,
+        EaseFunction::EaseInOut,
+        EaseFunction::SineIn,
+        EaseFunction::SineOut,
+        EaseFunction::SineInOut,
+        EaseFunction::QuadIn,
+        EaseFunction::QuadOut,
+        EaseFunction::QuadInOut,
+        EaseFunction::CubicIn,
+        EaseFunction::CubicOut,
+        EaseFunction::CubicInOut,
+        EaseFunction::BackIn,
+        EaseFunction::BackOut,
+        EaseFunction::BackInOut,
+    ];
+
+    #[test]
+    fn endpoints() {
+        for function in ALL {
+            assert_eq!(function.sample(0.0), 0.0, "{function:?}");
+            assert_eq!(function.sample(1.0), 1.0, "{function:?}");
+            // The input is clamped
+            assert_eq!(function.sample(-3.0), 0.0, "{function:?}");
+            assert_eq!(function.sample(2.0), 1.0, "{function:?}");
+        }
+    }
+
+    #[test]
+    fn continuous_at_endpoints() {
+        for function in ALL {
+            assert!(function.sample(1e-4).abs() < 1e-3, "{function:?}");
+            assert!(
+                (function.sample(1.0 - 1e-4) - 1.0).abs() < 1e-3,
+                "{function:?}"
+            );
+            // The halves of the in-out functions meet in the middle.
+            let (below, above) = (function.sample(0.5 - 1e-4), function.sample(0.5 + 1e-4));
+            assert!((below - above).abs() < 1e-2, "{function:?}");
+        }
+    }
+
+    #[test]
+    fn monotonic() {
+        for function in ALL {
+            if matches!(
+                function,
+                EaseFunction::BackIn | EaseFunction::BackOut | EaseFunction::BackInOut
+            ) {
+                continue;
+            }
+            let mut previous = 0.0;
+            for i in 1..=1000 {
+                let value = function.sample(i as f32 / 1000.0);
+                assert!(value >= previous, "{function:?} decreases at {i}");
+                previous = value;
+            }
+        }
+    }
+
+    #[test]
+    fn back_overshoots() {
+        let samples = (1..100).map(|i| i as f32 / 100.0);
+        assert!(samples
+            .clone()
+            .any(|t| EaseFunction::BackIn.sample(t) < -0.05));
+        assert!(samples
+            .clone()
+            .any(|t| EaseFunction::BackOut.sample(t) > 1.05));
+        assert!(samples
+            .clone()
+            .any(|t| EaseFunction::BackInOut.sample(t) < -0.05));
+        assert!(samples
+            .clone()
+            .any(|t| EaseFunction::BackInOut.sample(t) > 1.05));
+    }
+
+    /// Reference values of the CSS `ease-in-out` timing function.
+    #[test]
+    fn css_ease_in_out() {
+        let reference = [
+            (0.1, 0.01972),
+            (0.25, 0.12916),
+            (0.4, 0.33188),
+            (0.5, 0.5),
+            (0.6, 0.66812),
+            (0.75, 0.87084),
+            (0.9, 0.98028),
+        ];
+        for (t, expected) in reference {
+            let value = EaseFunction::EaseInOut.sample(t);
+            assert!(
+                (value - expected).abs() < 1e-4,
+                "{t}: {value} != {expected}"
+            );
+        }
+    }
+}
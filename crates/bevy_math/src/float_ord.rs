@@ -0,0 +1,262 @@
+//! Helpers for selecting items by a floating point key, such as a distance.
+//!
+//! Comparing floats with [`PartialOrd`] and unwrapping the result panics as soon as a `NaN`
+//! shows up, which is easy to hit with degenerate geometry. The functions in this module
+//! instead use a total order in which `NaN` keys sort *after* every other value, no matter
+//! whether the smallest or the largest key is requested. An item with a `NaN` key is therefore
+//! only ever returned if every key is `NaN`.
+//!
+//! Note that this differs from `bevy_utils::FloatOrd`, which sorts `NaN` before all other values
+//! and would make `min_by_key(|x| FloatOrd(..))` prefer `NaN` keys.
+//!
+//! Ties are always resolved in favor of the item that comes first in iteration order.
+
+use std::cmp::Ordering;
+
+use crate::{Vec2, Vec3};
+
+/// Compares two keys, ordering `NaN` after all other values, and equal to itself.
+#[inline]
+fn cmp_nan_last<F: PartialOrd + Copy>(a: F, b: F) -> Ordering {
+    // `NaN` is the only float that can't be compared to itself.
+    let is_nan = |x: F| x.partial_cmp(&x).is_none();
+    match (is_nan(a), is_nan(b)) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(&b).unwrap(),
+    }
+}
+
+/// Returns the item whose key best satisfies `is_better`, resolving ties to the first item.
+#[inline]
+fn select_by_key<T>(
+    items: impl IntoIterator<Item = T>,
+    mut key: impl FnMut(&T) -> f32,
+    is_better: impl Fn(f32, f32) -> bool,
+) -> Option<T> {
+    let mut items = items.into_iter();
+    let first = items.next()?;
+    let first_key = key(&first);
+    let (best, _) = items.fold((first, first_key), |(best, best_key), item| {
+        let item_key = key(&item);
+        if is_better(item_key, best_key) {
+            (item, item_key)
+        } else {
+            (best, best_key)
+        }
+    });
+    Some(best)
+}
+
+/// Returns the item with the smallest key, or `None` if `items` is empty.
+///
+/// Items with a `NaN` key are only returned if every key is `NaN`.
+/// Ties are resolved in favor of the first item.
+///
+/// # Examples
+///
+/// ```rust
+/// # use bevy_math::float_ord::nearest_by_key;
+/// let distances = [3.0, f32::NAN, 1.0, 1.0, 2.0];
+/// let nearest = nearest_by_key(distances.iter().enumerate(), |(_, d)| **d);
+/// assert_eq!(nearest.map(|(i, _)| i), Some(2));
+/// ```
+#[inline]
+pub fn nearest_by_key<T>(
+    items: impl IntoIterator<Item = T>,
+    key: impl FnMut(&T) -> f32,
+) -> Option<T> {
+    select_by_key(items, key, |candidate, best| {
+        cmp_nan_last(candidate, best) == Ordering::Less
+    })
+}
+
+/// Returns the item with the largest key, or `None` if `items` is empty.
+///
+/// Items with a `NaN` key are only returned if every key is `NaN`.
+/// Ties are resolved in favor of the first item.
+///
+/// # Examples
+///
+/// ```rust
+/// # use bevy_math::float_ord::farthest_by_key;
+/// let distances = [3.0, f32::NAN, 5.0, 5.0, 2.0];
+/// let farthest = farthest_by_key(distances.iter().enumerate(), |(_, d)| **d);
+/// assert_eq!(farthest.map(|(i, _)| i), Some(2));
+/// ```
+#[inline]
+pub fn farthest_by_key<T>(
+    items: impl IntoIterator<Item = T>,
+    key: impl FnMut(&T) -> f32,
+) -> Option<T> {
+    select_by_key(items, key, |candidate, best| {
+        if candidate.is_nan() || best.is_nan() {
+            cmp_nan_last(candidate, best) == Ordering::Less
+        } else {
+            candidate > best
+        }
+    })
+}
+
+/// Sorts `items` by an `f32` key, such as a distance, in ascending order with `NaN` keys last.
+///
+/// This doesn't preserve the order of items with equal keys, see [`slice::sort_unstable_by`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use bevy_math::{float_ord::sort_unstable_by_key_f32, Vec2};
+/// let mut points = [Vec2::new(3.0, 0.0), Vec2::NAN, Vec2::new(0.0, -1.0)];
+/// sort_unstable_by_key_f32(&mut points, |point| point.length_squared());
+/// assert_eq!(points[..2], [Vec2::new(0.0, -1.0), Vec2::new(3.0, 0.0)]);
+/// assert!(points[2].is_nan());
+/// ```
+#[inline]
+pub fn sort_unstable_by_key_f32<T>(items: &mut [T], mut key: impl FnMut(&T) -> f32) {
+    items.sort_unstable_by(|a, b| cmp_nan_last(key(a), key(b)));
+}
+
+/// Sorts `items` by an `f64` key, such as a distance, in ascending order with `NaN` keys last.
+///
+/// This doesn't preserve the order of items with equal keys, see [`slice::sort_unstable_by`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use bevy_math::float_ord::sort_unstable_by_key_f64;
+/// let mut distances = [4.0e12, f64::NAN, 1.0e12];
+/// sort_unstable_by_key_f64(&mut distances, |distance| *distance);
+/// assert_eq!(distances[..2], [1.0e12, 4.0e12]);
+/// ```
+#[inline]
+pub fn sort_unstable_by_key_f64<T>(items: &mut [T], mut key: impl FnMut(&T) -> f64) {
+    items.sort_unstable_by(|a, b| cmp_nan_last(key(a), key(b)));
+}
+
+/// Returns the point in `points` closest to `origin`, or `None` if there are no points.
+///
+/// Points at a `NaN` distance, e.g. points with a `NaN` component, are only returned if every
+/// distance is `NaN`. Ties are resolved in favor of the first point.
+///
+/// # Examples
+///
+/// ```rust
+/// # use bevy_math::{float_ord::nearest_point, Vec3};
+/// let points = [Vec3::X * 4.0, Vec3::NAN, Vec3::NEG_Y];
+/// assert_eq!(nearest_point(Vec3::ZERO, points), Some(Vec3::NEG_Y));
+/// ```
+#[inline]
+pub fn nearest_point(origin: Vec3, points: impl IntoIterator<Item = Vec3>) -> Option<Vec3> {
+    nearest_by_key(points, |point| point.distance_squared(origin))
+}
+
+/// Returns the point in `points` closest to `origin`, or `None` if there are no points.
+///
+/// Points at a `NaN` distance, e.g. points with a `NaN` component, are only returned if every
+/// distance is `NaN`. Ties are resolved in favor of the first point.
+///
+/// # Examples
+///
+/// ```rust
+/// # use bevy_math::{float_ord::nearest_point_2d, Vec2};
+/// let points = [Vec2::X * 4.0, Vec2::NAN, Vec2::NEG_Y];
+/// assert_eq!(nearest_point_2d(Vec2::ZERO, points), Some(Vec2::NEG_Y));
+/// ```
+#[inline]
+pub fn nearest_point_2d(origin: Vec2, points: impl IntoIterator<Item = Vec2>) -> Option<Vec2> {
+    nearest_by_key(points, |point| point.distance_squared(origin))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(nearest_by_key(Vec::<f32>::new(), |x| *x), None);
+        assert_eq!(farthest_by_key(Vec::<f32>::new(), |x| *x), None);
+        assert_eq!(nearest_point(Vec3::ZERO, []), None);
+        assert_eq!(nearest_point_2d(Vec2::ZERO, []), None);
+    }
+
+    #[test]
+    fn nan_keys_sort_last() {
+        let keys = [f32::NAN, 2.0, f32::NAN, -1.0, 7.0, f32::NAN];
+        assert_eq!(nearest_by_key(keys, |x| *x), Some(-1.0));
+        assert_eq!(farthest_by_key(keys, |x| *x), Some(7.0));
+
+        // Infinities are ordered normally
+        let keys = [f32::NAN, f32::INFINITY, f32::NEG_INFINITY];
+        assert_eq!(nearest_by_key(keys, |x| *x), Some(f32::NEG_INFINITY));
+        assert_eq!(farthest_by_key(keys, |x| *x), Some(f32::INFINITY));
+
+        // Only NaN keys left
+        let keys = [(0, f32::NAN), (1, f32::NAN)];
+        assert_eq!(nearest_by_key(keys, |(_, x)| *x).map(|(i, _)| i), Some(0));
+        assert_eq!(farthest_by_key(keys, |(_, x)| *x).map(|(i, _)| i), Some(0));
+    }
+
+    #[test]
+    fn ties_pick_first() {
+        let keys = [(0, 3.0), (1, 1.0), (2, 1.0), (3, 3.0)];
+        assert_eq!(nearest_by_key(keys, |(_, x)| *x).map(|(i, _)| i), Some(1));
+        assert_eq!(farthest_by_key(keys, |(_, x)| *x).map(|(i, _)| i), Some(0));
+
+        // Signed zeros compare equal
+        let keys = [(0, 0.0), (1, -0.0)];
+        assert_eq!(nearest_by_key(keys, |(_, x)| *x).map(|(i, _)| i), Some(0));
+    }
+
+    #[test]
+    fn nearest_points() {
+        let origin = Vec3::new(1.0, 1.0, 1.0);
+        let points = [
+            Vec3::new(f32::NAN, 1.0, 1.0),
+            Vec3::new(5.0, 1.0, 1.0),
+            Vec3::new(1.0, 0.0, 1.0),
+            Vec3::new(1.0, 1.0, 0.0),
+        ];
+        assert_eq!(
+            nearest_point(origin, points),
+            Some(Vec3::new(1.0, 0.0, 1.0))
+        );
+
+        let points = [Vec2::new(3.0, 0.0), Vec2::new(-2.0, 0.0), Vec2::NAN];
+        assert_eq!(
+            nearest_point_2d(Vec2::ZERO, points),
+            Some(Vec2::new(-2.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn sort_nan_last() {
+        let mut keys = [
+            f32::NAN,
+            2.0,
+            f32::INFINITY,
+            -f32::NAN,
+            -1.0,
+            f32::NEG_INFINITY,
+            -0.0,
+        ];
+        sort_unstable_by_key_f32(&mut keys, |x| *x);
+        assert_eq!(
+            keys[..5],
+            [f32::NEG_INFINITY, -1.0, -0.0, 2.0, f32::INFINITY]
+        );
+        assert!(keys[5..].iter().all(|x| x.is_nan()));
+
+        let mut keys = [(0, f64::NAN), (1, f64::INFINITY), (2, 1e300), (3, -1e300)];
+        sort_unstable_by_key_f64(&mut keys, |(_, x)| *x);
+        assert_eq!(keys.map(|(i, _)| i), [3, 2, 1, 0]);
+
+        // Sorting agrees with the nearest and farthest selection
+        let mut keys = [3.0, f32::NAN, 5.0, -2.0];
+        let nearest = nearest_by_key(keys, |x| *x);
+        let farthest = farthest_by_key(keys, |x| *x);
+        sort_unstable_by_key_f32(&mut keys, |x| *x);
+        assert_eq!(Some(keys[0]), nearest);
+        assert_eq!(Some(keys[2]), farthest);
+    }
+}
@@ -0,0 +1,340 @@
+//! Snapping of points and rectangles to a regular grid.
+
+use crate::{IRect, IVec2, Rect, Vec2, Vec3};
+
+/// An extension trait for snapping points to the nearest point of a regular grid.
+///
+/// The grid has a point at the origin and one every `cell_size` along each axis.
+///
+/// # Rounding Behaviour
+///
+/// Each component is snapped to the nearest multiple of the corresponding `cell_size`
+/// component. Values that lie exactly halfway between two grid points are rounded towards
+/// positive infinity, for negative coordinates too: with a cell size of `1.0`, both `0.5` and
+/// `-0.5` snap to the grid point above them (`1.0` and `0.0` respectively). Values that already
+/// lie on a grid point are returned unchanged.
+///
+/// The grid index is computed from the original value every time, so repeatedly snapping
+/// a point does not accumulate floating point error.
+///
+/// The components of `cell_size` are expected to be positive and finite.
+///
+/// # Examples
+///
+/// ```rust
+/// # use bevy_math::{GridSnapExt, Vec2};
+/// let snapped = Vec2::new(1.2, -3.7).snap_to_grid(Vec2::splat(0.5));
+/// assert_eq!(snapped, Vec2::new(1.0, -3.5));
+/// ```
+pub trait GridSnapExt: Sized {
+    /// Snap this point to the nearest point of a grid with cells of size `cell_size`.
+    ///
+    /// See the [trait-level documentation](GridSnapExt) for the rounding behaviour.
+    fn snap_to_grid(self, cell_size: Self) -> Self;
+}
+
+impl GridSnapExt for Vec2 {
+    #[inline]
+    fn snap_to_grid(self, cell_size: Self) -> Self {
+        (self / cell_size + 0.5).floor() * cell_size
+    }
+}
+
+impl GridSnapExt for Vec3 {
+    #[inline]
+    fn snap_to_grid(self, cell_size: Self) -> Self {
+        (self / cell_size + 0.5).floor() * cell_size
+    }
+}
+
+/// Returns the largest multiple of `cell_size` that is less than or equal to `value`.
+///
+/// The multiple is corrected by one cell if the division rounded up, so the result is
+/// guaranteed to never exceed `value`.
+#[inline]
+fn floor_to_grid(value: Vec2, cell_size: Vec2) -> Vec2 {
+    let snapped = (value / cell_size).floor() * cell_size;
+    Vec2::select(snapped.cmpgt(value), snapped - cell_size, snapped)
+}
+
+/// Returns the smallest multiple of `cell_size` that is greater than or equal to `value`.
+///
+/// The multiple is corrected by one cell if the division rounded down, so the result is
+/// guaranteed to never be less than `value`.
+#[inline]
+fn ceil_to_grid(value: Vec2, cell_size: Vec2) -> Vec2 {
+    let snapped = (value / cell_size).ceil() * cell_size;
+    Vec2::select(snapped.cmplt(value), snapped + cell_size, snapped)
+}
+
+/// Returns the largest multiple of `cell_size` that is less than or equal to `value`,
+/// using floor division so negative values round towards negative infinity.
+#[inline]
+fn floor_to_grid_i(value: IVec2, cell_size: IVec2) -> IVec2 {
+    value.div_euclid(cell_size) * cell_size
+}
+
+/// Returns the smallest multiple of `cell_size` that is greater than or equal to `value`,
+/// using floor division so negative values round towards positive infinity.
+#[inline]
+fn ceil_to_grid_i(value: IVec2, cell_size: IVec2) -> IVec2 {
+    -floor_to_grid_i(-value, cell_size)
+}
+
+impl Rect {
+    /// Expand this rectangle so that its corners lie on a grid with cells of size `cell_size`.
+    ///
+    /// The minimum corner is rounded down and the maximum corner is rounded up, towards
+    /// negative and positive infinity respectively, so the result always contains the original
+    /// rectangle. Corners that already lie on the grid are left unchanged.
+    ///
+    /// The components of `cell_size` are expected to be positive and finite.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_math::{Rect, Vec2};
+    /// let r = Rect::new(-1.5, 0.2, 2.5, 3.);
+    /// let snapped = r.snap_outward_to_grid(Vec2::splat(2.));
+    /// assert_eq!(snapped, Rect::new(-2., 0., 4., 4.));
+    /// ```
+    #[inline]
+    pub fn snap_outward_to_grid(&self, cell_size: Vec2) -> Self {
+        Self {
+            min: floor_to_grid(self.min, cell_size),
+            max: ceil_to_grid(self.max, cell_size),
+        }
+    }
+
+    /// Shrink this rectangle so that its corners lie on a grid with cells of size `cell_size`.
+    ///
+    /// The minimum corner is rounded up and the maximum corner is rounded down, so the result
+    /// is always contained in the original rectangle. Corners that already lie on the grid are
+    /// left unchanged. If no whole grid cell fits in the rectangle along an axis, the result is
+    /// empty ([`Rect::is_empty()`] returns `true`), with both corners collapsed onto the edge of
+    /// the original rectangle along that axis, which may not lie on the grid.
+    ///
+    /// The components of `cell_size` are expected to be positive and finite.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_math::{Rect, Vec2};
+    /// let r = Rect::new(-1.5, 0.2, 2.5, 3.);
+    /// let snapped = r.snap_inward_to_grid(Vec2::splat(1.));
+    /// assert_eq!(snapped, Rect::new(-1., 1., 2., 3.));
+    /// ```
+    #[inline]
+    pub fn snap_inward_to_grid(&self, cell_size: Vec2) -> Self {
+        let mut r = Self {
+            min: ceil_to_grid(self.min, cell_size),
+            max: floor_to_grid(self.max, cell_size),
+        };
+        // Collapse min over max to enforce invariants and ensure e.g. width() or
+        // height() never return a negative value. The collapsed corners are clamped
+        // into the original rect, as rounding may have moved them outside of it.
+        let empty = r.min.cmpgt(r.max);
+        let collapsed = r.max.max(self.min).min(self.max);
+        r.min = Vec2::select(empty, collapsed, r.min);
+        r.max = Vec2::select(empty, collapsed, r.max);
+        r
+    }
+}
+
+impl IRect {
+    /// Expand this rectangle so that its corners lie on a grid with cells of size `cell_size`.
+    ///
+    /// The minimum corner is rounded down and the maximum corner is rounded up using floor
+    /// division, so negative coordinates round away from the rectangle rather than towards
+    /// zero, and the result always contains the original rectangle.
+    ///
+    /// The components of `cell_size` are expected to be positive.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if any of the components of `cell_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_math::{IRect, IVec2};
+    /// let r = IRect::new(-3, 1, 5, 4);
+    /// let snapped = r.snap_outward_to_grid(IVec2::splat(4));
+    /// assert_eq!(snapped, IRect::new(-4, 0, 8, 4));
+    /// ```
+    #[inline]
+    pub fn snap_outward_to_grid(&self, cell_size: IVec2) -> Self {
+        Self {
+            min: floor_to_grid_i(self.min, cell_size),
+            max: ceil_to_grid_i(self.max, cell_size),
+        }
+    }
+
+    /// Shrink this rectangle so that its corners lie on a grid with cells of size `cell_size`.
+    ///
+    /// The minimum corner is rounded up and the maximum corner is rounded down using floor
+    /// division, so the result is always contained in the original rectangle. If no whole grid
+    /// cell fits in the rectangle along an axis, the result is empty ([`IRect::is_empty()`]
+    /// returns `true`), with both corners collapsed onto the edge of the original rectangle
+    /// along that axis, which may not lie on the grid.
+    ///
+    /// The components of `cell_size` are expected to be positive.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if any of the components of `cell_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_math::{IRect, IVec2};
+    /// let r = IRect::new(-3, 1, 5, 4);
+    /// let snapped = r.snap_inward_to_grid(IVec2::splat(2));
+    /// assert_eq!(snapped, IRect::new(-2, 2, 4, 4));
+    /// ```
+    #[inline]
+    pub fn snap_inward_to_grid(&self, cell_size: IVec2) -> Self {
+        let mut r = Self {
+            min: ceil_to_grid_i(self.min, cell_size),
+            max: floor_to_grid_i(self.max, cell_size),
+        };
+        // Collapse min over max to enforce invariants and ensure e.g. width() or
+        // height() never return a negative value. The collapsed corners are clamped
+        // into the original rect, as rounding may have moved them outside of it.
+        let empty = r.min.cmpgt(r.max);
+        let collapsed = r.max.max(self.min).min(self.max);
+        r.min = IVec2::select(empty, collapsed, r.min);
+        r.max = IVec2::select(empty, collapsed, r.max);
+        r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snap_point() {
+        let cell = Vec2::new(1., 0.25);
+
+        // Exactly on the grid
+        assert_eq!(
+            Vec2::new(3., -0.75).snap_to_grid(cell),
+            Vec2::new(3., -0.75)
+        );
+        // Nearest grid point
+        assert_eq!(Vec2::new(2.6, 0.3).snap_to_grid(cell), Vec2::new(3., 0.25));
+        assert_eq!(
+            Vec2::new(-2.6, -0.3).snap_to_grid(cell),
+            Vec2::new(-3., -0.25)
+        );
+        // Halfway values round towards positive infinity, also for negative values
+        assert_eq!(
+            Vec2::new(0.5, 0.125).snap_to_grid(cell),
+            Vec2::new(1., 0.25)
+        );
+        assert_eq!(
+            Vec2::new(-0.5, -0.125).snap_to_grid(cell),
+            Vec2::new(0., 0.)
+        );
+        assert_eq!(
+            Vec2::new(-1.5, -0.375).snap_to_grid(cell),
+            Vec2::new(-1., -0.25)
+        );
+
+        let snapped = Vec3::new(-7.9, 0.1, 4.2).snap_to_grid(Vec3::splat(2.));
+        assert_eq!(snapped, Vec3::new(-8., 0., 4.));
+    }
+
+    #[test]
+    fn snap_point_is_idempotent() {
+        let cell = Vec2::splat(0.1);
+        let mut p = Vec2::new(-13.37, 4.21);
+        let first = p.snap_to_grid(cell);
+        for _ in 0..100 {
+            p = p.snap_to_grid(cell);
+            assert_eq!(p, first);
+        }
+    }
+
+    #[test]
+    fn rect_snap_outward() {
+        let cell = Vec2::new(1., 0.5);
+
+        // Negative coordinates use floor semantics, not truncation
+        let r = Rect::new(-1.2, -0.3, 0.7, 0.6);
+        assert_eq!(r.snap_outward_to_grid(cell), Rect::new(-2., -0.5, 1., 1.));
+
+        // Corners exactly on the grid are unchanged
+        let r = Rect::new(-2., -1.5, 3., 0.5);
+        assert_eq!(r.snap_outward_to_grid(cell), r);
+
+        // The result always contains the original rect
+        let cell = Vec2::new(0.1, 0.3);
+        for i in 0..1000 {
+            let f = i as f32 * 0.0137 - 6.;
+            let r = Rect::new(f, -f * 0.7, f + 0.31, -f * 0.7 + 0.07);
+            let snapped = r.snap_outward_to_grid(cell);
+            assert!(snapped.contains(r.min));
+            assert!(snapped.contains(r.max));
+        }
+    }
+
+    #[test]
+    fn rect_snap_inward() {
+        let cell = Vec2::new(1., 0.5);
+
+        let r = Rect::new(-1.2, -0.3, 1.7, 0.6);
+        assert_eq!(r.snap_inward_to_grid(cell), Rect::new(-1., 0., 1., 0.5));
+
+        // Corners exactly on the grid are unchanged
+        let r = Rect::new(-2., -1.5, 3., 0.5);
+        assert_eq!(r.snap_inward_to_grid(cell), r);
+
+        // No whole cell fits, and the empty result stays inside the original rect
+        let r = Rect::new(0.1, 0.1, 0.9, 0.9);
+        assert!(r.snap_inward_to_grid(cell).is_empty());
+        let r = Rect::new(0.2, 0.2, 0.8, 0.8);
+        let snapped = r.snap_inward_to_grid(Vec2::ONE);
+        assert!(snapped.is_empty());
+        assert!(r.contains(snapped.min) && r.contains(snapped.max));
+        let r = Rect::new(-1.7, 0.2, 1.7, 0.8);
+        let snapped = r.snap_inward_to_grid(Vec2::ONE);
+        assert_eq!(snapped.min.x, -1.);
+        assert_eq!(snapped.max.x, 1.);
+        assert!(snapped.is_empty());
+        assert!(r.contains(snapped.min) && r.contains(snapped.max));
+
+        // The result is always contained in the original rect
+        let cell = Vec2::new(0.1, 0.3);
+        for i in 0..1000 {
+            let f = i as f32 * 0.0137 - 6.;
+            let r = Rect::new(f, -f * 0.7, f + 2.31, -f * 0.7 + 1.07);
+            let snapped = r.snap_inward_to_grid(cell);
+            assert!(r.contains(snapped.min));
+            assert!(r.contains(snapped.max));
+        }
+    }
+
+    #[test]
+    fn irect_snap() {
+        let cell = IVec2::new(4, 3);
+
+        let r = IRect::new(-5, -1, 5, 7);
+        assert_eq!(r.snap_outward_to_grid(cell), IRect::new(-8, -3, 8, 9));
+        assert_eq!(r.snap_inward_to_grid(cell), IRect::new(-4, 0, 4, 6));
+
+        // Corners exactly on the grid are unchanged
+        let r = IRect::new(-8, -3, 4, 6);
+        assert_eq!(r.snap_outward_to_grid(cell), r);
+        assert_eq!(r.snap_inward_to_grid(cell), r);
+
+        // No whole cell fits, and the empty result stays inside the original rect
+        let r = IRect::new(-3, 1, -1, 2);
+        let inward = r.snap_inward_to_grid(cell);
+        assert!(inward.is_empty());
+        assert!(r.contains(inward.min) && r.contains(inward.max));
+        let outward = r.snap_outward_to_grid(cell);
+        assert!(outward.contains(r.min) && outward.contains(r.max));
+    }
+}
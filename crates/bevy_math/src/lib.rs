@@ -9,12 +9,21 @@
 
 mod affine3;
 pub mod cubic_splines;
+mod easing;
+pub mod float_ord;
+mod grid_snap;
 mod ray;
+pub mod rect_pack;
 mod rects;
+mod smooth_damp;
+pub mod spatial_hash;
 
 pub use affine3::*;
-pub use ray::Ray;
+pub use easing::EaseFunction;
+pub use grid_snap::GridSnapExt;
+pub use ray::{Backfaces, Ray, RayTriangleHit};
 pub use rects::*;
+pub use smooth_damp::{smooth_damp_angle, smooth_damp_rotation, SmoothDamp};
 
 /// The `bevy_math` prelude.
 pub mod prelude {
@@ -22,10 +31,11 @@ pub mod prelude {
     pub use crate::{
         cubic_splines::{
             BSpline, CardinalSpline, CubicBezier, CubicGenerator, CubicSegment, Hermite,
+            LinearSpline, QuadraticBezier,
         },
-        BVec2, BVec3, BVec4, EulerRot, IRect, IVec2, IVec3, IVec4, Mat2, Mat3, Mat4, Quat, Ray,
-        Rect, URect, UVec2, UVec3, UVec4, Vec2, Vec2Swizzles, Vec3, Vec3Swizzles, Vec4,
-        Vec4Swizzles,
+        BVec2, BVec3, BVec4, EaseFunction, EulerRot, GridSnapExt, IRect, IVec2, IVec3, IVec4, Mat2,
+        Mat3, Mat4, Quat, Ray, Rect, SmoothDamp, URect, UVec2, UVec3, UVec4, Vec2, Vec2Swizzles,
+        Vec3, Vec3Swizzles, Vec4, Vec4Swizzles,
     };
 }
 
@@ -1,4 +1,4 @@
-use crate::Vec3;
+use crate::{Vec2, Vec3};
 
 /// A ray is an infinite line starting at `origin`, going in `direction`.
 #[derive(Default, Clone, Copy, Debug, PartialEq)]
@@ -10,8 +10,56 @@ pub struct Ray {
     pub direction: Vec3,
 }
 
+/// Whether [`Ray::intersect_triangle`] reports hits on the back face of a triangle.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum Backfaces {
+    /// Only hit triangles whose vertices appear counterclockwise as seen from the ray's origin.
+    Cull,
+    /// Hit triangles from both sides.
+    #[default]
+    Include,
+}
+
+/// An intersection between a [`Ray`] and a triangle, see [`Ray::intersect_triangle`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct RayTriangleHit {
+    /// The distance along the ray to the hit.
+    pub distance: f32,
+    /// The barycentric coordinates of the hit with respect to the second and third vertex.
+    ///
+    /// The hit point is `a + (b - a) * x + (c - a) * y` for the triangle `[a, b, c]`.
+    pub barycentric_coords: Vec2,
+    /// The normal of the front face of the triangle, `(b - a).cross(c - a).normalize()`.
+    ///
+    /// This follows the winding of the triangle, so it points away from the ray when a back
+    /// face is hit with [`Backfaces::Include`].
+    pub normal: Vec3,
+}
+
 impl Ray {
     /// Returns the distance to the plane if the ray intersects it.
+    ///
+    /// Rays that are parallel to the plane or point away from it never intersect it, while rays
+    /// starting on the plane intersect it at a distance of `0`.
+    ///
+    /// # Examples
+    ///
+    /// Picking a point on the ground with a ray cast from the camera, such as one returned by
+    /// `Camera::viewport_to_world`:
+    ///
+    /// ```
+    /// # use bevy_math::{Ray, Vec3};
+    /// let ray = Ray {
+    ///     origin: Vec3::new(0.0, 10.0, 10.0),
+    ///     direction: Vec3::new(0.0, -1.0, -1.0).normalize(),
+    /// };
+    /// if let Some(distance) = ray.intersect_plane(Vec3::ZERO, Vec3::Y) {
+    ///     let ground_point = ray.get_point(distance);
+    ///     assert!(ground_point.abs_diff_eq(Vec3::ZERO, 1e-5));
+    /// }
+    /// ```
     #[inline]
     pub fn intersect_plane(&self, plane_origin: Vec3, plane_normal: Vec3) -> Option<f32> {
         let denominator = plane_normal.dot(self.direction);
@@ -20,10 +68,86 @@ impl Ray {
             if distance > f32::EPSILON {
                 return Some(distance);
             }
+            if distance.abs() <= f32::EPSILON {
+                return Some(0.0);
+            }
         }
         None
     }
 
+    /// Returns the distance to the first intersection with a sphere if the ray hits it.
+    ///
+    /// If the ray starts inside the sphere, the distance to the point where it exits the sphere
+    /// is returned. Rays that only graze the sphere are counted as hits, up to a small tolerance.
+    ///
+    /// The outward surface normal at the hit is `(self.get_point(distance) - center) / radius`.
+    #[inline]
+    pub fn intersect_sphere(&self, center: Vec3, radius: f32) -> Option<f32> {
+        let offset = self.origin - center;
+        let half_b = offset.dot(self.direction);
+        let c = offset.length_squared() - radius * radius;
+        let discriminant = half_b * half_b - c;
+        // Allow for rounding error in the discriminant so that tangent rays aren't missed.
+        if discriminant < -4.0 * f32::EPSILON * offset.length_squared() {
+            return None;
+        }
+        let root = discriminant.max(0.0).sqrt();
+        let (near, far) = (-half_b - root, -half_b + root);
+        if near > f32::EPSILON {
+            Some(near)
+        } else if far > f32::EPSILON {
+            Some(far)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the hit if the ray intersects the triangle `[a, b, c]`.
+    ///
+    /// Hits exactly on an edge or vertex count as intersections, while rays parallel to the
+    /// triangle's plane never hit it. The front face of the triangle is the side from which its
+    /// vertices appear counterclockwise, and its normal is `(b - a).cross(c - a).normalize()`.
+    ///
+    /// This uses the Möller–Trumbore algorithm.
+    #[inline]
+    pub fn intersect_triangle(
+        &self,
+        [a, b, c]: [Vec3; 3],
+        backfaces: Backfaces,
+    ) -> Option<RayTriangleHit> {
+        let edge_ab = b - a;
+        let edge_ac = c - a;
+        let p = self.direction.cross(edge_ac);
+        let determinant = edge_ab.dot(p);
+
+        // The determinant is negative for back faces and close to zero for parallel rays. It
+        // scales with the area of the triangle, so the tolerance does too.
+        let parallel = determinant.abs() <= f32::EPSILON * edge_ab.length() * edge_ac.length();
+        if parallel || (backfaces == Backfaces::Cull && determinant < 0.0) {
+            return None;
+        }
+
+        let inverse_determinant = determinant.recip();
+        let offset = self.origin - a;
+        let u = offset.dot(p) * inverse_determinant;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+
+        let q = offset.cross(edge_ab);
+        let v = self.direction.dot(q) * inverse_determinant;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let distance = edge_ac.dot(q) * inverse_determinant;
+        (distance > 0.0).then(|| RayTriangleHit {
+            distance,
+            barycentric_coords: Vec2::new(u, v),
+            normal: edge_ab.cross(edge_ac).normalize(),
+        })
+    }
+
     /// Retrieve a point at the given distance along the ray.
     #[inline]
     pub fn get_point(&self, distance: f32) -> Vec3 {
@@ -61,4 +185,187 @@ mod tests {
             ray.intersect_plane(Vec3::X, Vec3::X + Vec3::Z * f32::EPSILON)
         );
     }
+
+    #[test]
+    fn intersect_plane_degenerate() {
+        let plane_normal = Vec3::new(0., 1., 1.).normalize();
+
+        // Starting on the plane, whether pointing into, along or away from it
+        for direction in [Vec3::NEG_Y, Vec3::Y, Vec3::X, plane_normal] {
+            let ray = Ray {
+                origin: Vec3::ZERO,
+                direction,
+            };
+            let expected = (direction != Vec3::X).then_some(0.);
+            assert_eq!(expected, ray.intersect_plane(Vec3::ZERO, plane_normal));
+        }
+
+        // Parallel above the plane, and pointing away from it
+        let ray = Ray {
+            origin: Vec3::Y,
+            direction: Vec3::X,
+        };
+        assert_eq!(None, ray.intersect_plane(Vec3::ZERO, Vec3::Y));
+        let ray = Ray {
+            origin: Vec3::Y,
+            direction: Vec3::Y,
+        };
+        assert_eq!(None, ray.intersect_plane(Vec3::ZERO, Vec3::Y));
+        assert_eq!(None, ray.intersect_plane(Vec3::ZERO, Vec3::NEG_Y));
+    }
+
+    #[test]
+    fn intersect_sphere() {
+        let ray = Ray {
+            origin: Vec3::ZERO,
+            direction: Vec3::Z,
+        };
+
+        // In front of the ray
+        assert_eq!(Some(4.), ray.intersect_sphere(Vec3::Z * 5., 1.));
+        // Behind the ray
+        assert_eq!(None, ray.intersect_sphere(Vec3::NEG_Z * 5., 1.));
+        // Missed
+        assert_eq!(None, ray.intersect_sphere(Vec3::new(2., 0., 5.), 1.));
+
+        // Starting inside returns the exit point
+        assert_eq!(Some(3.), ray.intersect_sphere(Vec3::Z, 2.));
+        // Starting on the surface and leaving the sphere
+        assert_eq!(None, ray.intersect_sphere(Vec3::NEG_Z, 1.));
+
+        // Tangent rays are hits with a finite normal
+        for radius in [1., 0.3, 7.1] {
+            let center = Vec3::new(radius, 0., 5.);
+            let distance = ray.intersect_sphere(center, radius).unwrap();
+            assert!((distance - 5.).abs() < 1e-3);
+            let normal = (ray.get_point(distance) - center) / radius;
+            assert!(normal.is_finite());
+            assert!(normal.abs_diff_eq(Vec3::NEG_X, 1e-2));
+        }
+    }
+
+    #[test]
+    fn intersect_triangle() {
+        let ray = Ray {
+            origin: Vec3::ZERO,
+            direction: Vec3::Z,
+        };
+        // Counterclockwise when seen from the origin, so the front face points towards it.
+        let triangle = [
+            Vec3::new(-1., -1., 2.),
+            Vec3::new(0., 1., 2.),
+            Vec3::new(1., -1., 2.),
+        ];
+        let reversed = [triangle[0], triangle[2], triangle[1]];
+
+        let hit = ray.intersect_triangle(triangle, Backfaces::Cull).unwrap();
+        assert_eq!(hit.distance, 2.);
+        assert_eq!(hit.barycentric_coords, Vec2::new(0.5, 0.25));
+        assert_eq!(hit.normal, Vec3::NEG_Z);
+        let [a, b, c] = triangle;
+        let point = a + (b - a) * hit.barycentric_coords.x + (c - a) * hit.barycentric_coords.y;
+        assert_eq!(point, ray.get_point(hit.distance));
+
+        // Back faces
+        assert_eq!(None, ray.intersect_triangle(reversed, Backfaces::Cull));
+        let hit = ray
+            .intersect_triangle(reversed, Backfaces::Include)
+            .unwrap();
+        assert_eq!(hit.distance, 2.);
+        // The normal follows the winding, so it points away from the ray for back faces.
+        assert_eq!(hit.normal, Vec3::Z);
+
+        // Behind the ray
+        let behind = triangle.map(|v| v * Vec3::new(1., 1., -1.));
+        assert_eq!(None, ray.intersect_triangle(behind, Backfaces::Include));
+
+        // Missed
+        let offset = triangle.map(|v| v + Vec3::X * 3.);
+        assert_eq!(None, ray.intersect_triangle(offset, Backfaces::Include));
+    }
+
+    #[test]
+    fn intersect_triangle_edges_and_vertices() {
+        let triangle = [Vec3::ZERO, Vec3::X, Vec3::Y];
+        let ray_at = |x: f32, y: f32| Ray {
+            origin: Vec3::new(x, y, -1.),
+            direction: Vec3::Z,
+        };
+
+        // Vertices
+        for (i, vertex) in triangle.into_iter().enumerate() {
+            let hit = ray_at(vertex.x, vertex.y).intersect_triangle(triangle, Backfaces::Include);
+            assert_eq!(hit.map(|hit| hit.distance), Some(1.), "vertex {i}");
+        }
+
+        // Edges
+        for (x, y) in [(0.5, 0.), (0., 0.5), (0.5, 0.5)] {
+            let hit = ray_at(x, y).intersect_triangle(triangle, Backfaces::Include);
+            let hit = hit.unwrap_or_else(|| panic!("edge at ({x}, {y})"));
+            assert_eq!(hit.distance, 1.);
+            assert_eq!(hit.normal, Vec3::Z);
+        }
+
+        // Just outside of the edges
+        for (x, y) in [(0.5, -1e-4), (-1e-4, 0.5), (0.5, 0.5001)] {
+            let hit = ray_at(x, y).intersect_triangle(triangle, Backfaces::Include);
+            assert_eq!(hit, None, "outside at ({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn intersect_triangle_parallel() {
+        let triangle = [Vec3::ZERO, Vec3::X, Vec3::Y];
+
+        // In the triangle's plane, even when passing through it
+        let ray = Ray {
+            origin: Vec3::new(-1., 0.25, 0.),
+            direction: Vec3::X,
+        };
+        assert_eq!(None, ray.intersect_triangle(triangle, Backfaces::Include));
+
+        // Parallel to the triangle's plane
+        let ray = Ray {
+            origin: Vec3::new(-1., 0.25, 1.),
+            direction: Vec3::X,
+        };
+        assert_eq!(None, ray.intersect_triangle(triangle, Backfaces::Include));
+
+        // Degenerate triangle
+        let ray = Ray {
+            origin: Vec3::new(0.5, 0., -1.),
+            direction: Vec3::Z,
+        };
+        let degenerate = [Vec3::ZERO, Vec3::X, Vec3::X * 2.];
+        assert_eq!(None, ray.intersect_triangle(degenerate, Backfaces::Include));
+    }
+
+    #[test]
+    fn intersect_tiny_triangle() {
+        // The same triangle as in `intersect_triangle`, at a much smaller scale
+        let scale = 1e-5;
+        let triangle = [
+            Vec3::new(-1., -1., 2.),
+            Vec3::new(0., 1., 2.),
+            Vec3::new(1., -1., 2.),
+        ]
+        .map(|v| v * scale);
+        let ray = Ray {
+            origin: Vec3::ZERO,
+            direction: Vec3::Z,
+        };
+        let hit = ray.intersect_triangle(triangle, Backfaces::Cull).unwrap();
+        assert!((hit.distance - 2. * scale).abs() <= 1e-6 * scale);
+        assert!(hit
+            .barycentric_coords
+            .abs_diff_eq(Vec2::new(0.5, 0.25), 1e-5));
+        assert!(hit.normal.abs_diff_eq(Vec3::NEG_Z, 1e-6));
+
+        // Still parallel at that scale
+        let ray = Ray {
+            origin: Vec3::new(-scale, 0., 2. * scale),
+            direction: Vec3::X,
+        };
+        assert_eq!(None, ray.intersect_triangle(triangle, Backfaces::Include));
+    }
 }
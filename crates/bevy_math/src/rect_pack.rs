@@ -0,0 +1,337 @@
+//! Packing of rectangles into a bounded area, e.g. for building texture atlases at runtime.
+//!
+//! The packer uses the skyline bottom-left heuristic: it keeps track of the top edge of the
+//! already packed rectangles as a list of horizontal segments, and places each new rectangle
+//! as low as possible, and then as far left as possible, on that skyline.
+//!
+//! Packing is fully deterministic: the same sequence of sizes always produces the same layout.
+
+use crate::{URect, UVec2};
+
+/// Packs rectangles of the given `sizes` into an area of `max_size`.
+///
+/// Returns the rectangle assigned to each size, in the same order as `sizes`, or `None` if they
+/// do not all fit. The returned rectangles never overlap and never extend beyond `max_size`.
+///
+/// Rectangles are inserted from tallest to shortest, which usually results in a much tighter
+/// packing than inserting them in the given order. Use [`RectPacker`] to insert rectangles
+/// one by one instead, or to add padding between them.
+///
+/// # Examples
+///
+/// ```rust
+/// # use bevy_math::{rect_pack::pack_rects, URect, UVec2};
+/// let sizes = [UVec2::new(4, 2), UVec2::new(2, 4), UVec2::new(2, 2)];
+/// let rects = pack_rects(&sizes, UVec2::new(6, 4)).unwrap();
+/// assert_eq!(rects[1], URect::new(0, 0, 2, 4));
+/// assert_eq!(rects[0], URect::new(2, 0, 6, 2));
+/// assert_eq!(rects[2], URect::new(2, 2, 4, 4));
+///
+/// assert!(pack_rects(&sizes, UVec2::new(5, 4)).is_none());
+/// ```
+pub fn pack_rects(sizes: &[UVec2], max_size: UVec2) -> Option<Vec<URect>> {
+    let mut order: Vec<usize> = (0..sizes.len()).collect();
+    // Stable sort, so equally sized rects keep their relative order.
+    order.sort_by(|&a, &b| {
+        let (a, b) = (sizes[a], sizes[b]);
+        b.y.cmp(&a.y).then(b.x.cmp(&a.x))
+    });
+
+    let mut packer = RectPacker::new(max_size);
+    let mut rects = vec![URect::default(); sizes.len()];
+    for index in order {
+        rects[index] = packer.insert(sizes[index])?;
+    }
+    Some(rects)
+}
+
+/// A horizontal segment of the skyline, spanning `x..x + width` at height `y`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct SkylineSegment {
+    x: u32,
+    y: u32,
+    width: u32,
+}
+
+/// An incremental rectangle packer using the skyline bottom-left heuristic.
+///
+/// Rectangles are inserted one at a time with [`RectPacker::insert`], and are never moved once
+/// placed. This makes it suitable for atlases that grow over time, e.g. glyph caches.
+///
+/// # Examples
+///
+/// ```rust
+/// # use bevy_math::{rect_pack::RectPacker, URect, UVec2};
+/// let mut packer = RectPacker::new(UVec2::new(8, 8)).with_padding(1);
+/// assert_eq!(packer.insert(UVec2::new(3, 3)), Some(URect::new(0, 0, 3, 3)));
+/// assert_eq!(packer.insert(UVec2::new(3, 3)), Some(URect::new(4, 0, 7, 3)));
+/// assert_eq!(packer.insert(UVec2::new(8, 5)), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RectPacker {
+    max_size: UVec2,
+    padding: u32,
+    /// The top edge of the packed area, sorted by `x`, covering `0..max_size.x` without gaps.
+    skyline: Vec<SkylineSegment>,
+}
+
+impl RectPacker {
+    /// Create a new empty packer for an area of `max_size`.
+    pub fn new(max_size: UVec2) -> Self {
+        Self {
+            max_size,
+            padding: 0,
+            skyline: vec![SkylineSegment {
+                x: 0,
+                y: 0,
+                width: max_size.x,
+            }],
+        }
+    }
+
+    /// Keep at least `padding` units of free space between packed rectangles.
+    ///
+    /// No padding is added between the rectangles and the borders of the packing area.
+    pub fn with_padding(mut self, padding: u32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// The size of the area rectangles are packed into.
+    pub fn max_size(&self) -> UVec2 {
+        self.max_size
+    }
+
+    /// The padding kept between packed rectangles.
+    pub fn padding(&self) -> u32 {
+        self.padding
+    }
+
+    /// Find a free spot for a rectangle of the given `size` and mark it as used.
+    ///
+    /// Returns `None`, leaving the packer unchanged, if there is no space left for it.
+    ///
+    /// Rectangles with a zero width or height take up no space, and are placed at the origin.
+    pub fn insert(&mut self, size: UVec2) -> Option<URect> {
+        if size.cmpgt(self.max_size).any() {
+            return None;
+        }
+        if size.x == 0 || size.y == 0 {
+            return Some(URect::from_corners(UVec2::ZERO, size));
+        }
+
+        // Find the lowest, then leftmost, position along the skyline where the rect fits.
+        let (index, y) = (0..self.skyline.len())
+            .filter_map(|index| Some((index, self.fit(index, size)?)))
+            .min_by_key(|&(index, y)| (y, self.skyline[index].x))?;
+
+        let x = self.skyline[index].x;
+        let footprint_end = self.footprint_end(x, size.x);
+        self.add_segment(
+            index,
+            SkylineSegment {
+                x,
+                y: y.saturating_add(size.y).saturating_add(self.padding),
+                width: footprint_end - x,
+            },
+        );
+
+        Some(URect::from_corners(
+            UVec2::new(x, y),
+            UVec2::new(x + size.x, y + size.y),
+        ))
+    }
+
+    /// The right edge of the area reserved for a rect of the given `width` starting at `x`,
+    /// including its padding but clamped to the packing area.
+    #[inline]
+    fn footprint_end(&self, x: u32, width: u32) -> u32 {
+        x.saturating_add(width)
+            .saturating_add(self.padding)
+            .min(self.max_size.x)
+    }
+
+    /// Returns the `y` position a rect of `size` would be placed at if its left edge was at
+    /// the start of the skyline segment at `index`, or `None` if it doesn't fit there.
+    fn fit(&self, index: usize, size: UVec2) -> Option<u32> {
+        let x = self.skyline[index].x;
+        if x + size.x > self.max_size.x {
+            return None;
+        }
+
+        let footprint_end = self.footprint_end(x, size.x);
+        let y = self.skyline[index..]
+            .iter()
+            .take_while(|segment| segment.x < footprint_end)
+            .map(|segment| segment.y)
+            .max()?;
+
+        (y.saturating_add(size.y) <= self.max_size.y).then_some(y)
+    }
+
+    /// Insert `segment` at `index`, shadowing the parts of the following segments it covers.
+    fn add_segment(&mut self, index: usize, segment: SkylineSegment) {
+        let end = segment.x + segment.width;
+        self.skyline.insert(index, segment);
+
+        let next = index + 1;
+        while next < self.skyline.len() && self.skyline[next].x < end {
+            let covered = &mut self.skyline[next];
+            let covered_end = covered.x + covered.width;
+            if covered_end <= end {
+                self.skyline.remove(next);
+            } else {
+                covered.width = covered_end - end;
+                covered.x = end;
+                break;
+            }
+        }
+
+        // Merge neighboring segments at the same height.
+        self.skyline.dedup_by(|next, previous| {
+            if next.y == previous.y {
+                previous.width += next.width;
+                true
+            } else {
+                false
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn overlap(a: URect, b: URect, padding: u32) -> bool {
+        a.min.x < b.max.x + padding
+            && b.min.x < a.max.x + padding
+            && a.min.y < b.max.y + padding
+            && b.min.y < a.max.y + padding
+    }
+
+    fn assert_valid(sizes: &[UVec2], rects: &[URect], max_size: UVec2, padding: u32) {
+        assert_eq!(sizes.len(), rects.len());
+        for (size, rect) in sizes.iter().zip(rects) {
+            assert_eq!(rect.size(), *size);
+            assert!(
+                rect.max.cmple(max_size).all(),
+                "{rect:?} exceeds {max_size}"
+            );
+        }
+        for (i, a) in rects.iter().enumerate() {
+            for b in &rects[i + 1..] {
+                assert!(!overlap(*a, *b, padding), "{a:?} overlaps {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn random_sizes_never_overlap() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for max_size in [
+            UVec2::new(256, 256),
+            UVec2::new(1024, 64),
+            UVec2::new(61, 333),
+        ] {
+            for _ in 0..20 {
+                let sizes: Vec<UVec2> = (0..100)
+                    .map(|_| UVec2::new(rng.gen_range(1..=24), rng.gen_range(1..=24)))
+                    .collect();
+                if let Some(rects) = pack_rects(&sizes, max_size) {
+                    assert_valid(&sizes, &rects, max_size, 0);
+                }
+
+                let mut packer = RectPacker::new(max_size).with_padding(2);
+                let (sizes, rects): (Vec<_>, Vec<_>) = sizes
+                    .iter()
+                    .filter_map(|size| Some((*size, packer.insert(*size)?)))
+                    .unzip();
+                assert!(!rects.is_empty());
+                assert_valid(&sizes, &rects, max_size, 2);
+            }
+        }
+    }
+
+    #[test]
+    fn exact_fit() {
+        // Sixteen 4x4 squares exactly tile a 16x16 area.
+        let sizes = [UVec2::splat(4); 16];
+        let rects = pack_rects(&sizes, UVec2::splat(16)).unwrap();
+        assert_valid(&sizes, &rects, UVec2::splat(16), 0);
+
+        // One more doesn't fit.
+        assert!(pack_rects(&[UVec2::splat(4); 17], UVec2::splat(16)).is_none());
+    }
+
+    #[test]
+    fn too_large() {
+        let mut packer = RectPacker::new(UVec2::new(10, 5));
+        assert_eq!(packer.insert(UVec2::new(11, 1)), None);
+        assert_eq!(packer.insert(UVec2::new(1, 6)), None);
+        assert_eq!(packer.insert(UVec2::MAX), None);
+        assert_eq!(
+            packer.insert(UVec2::new(10, 5)),
+            Some(URect::new(0, 0, 10, 5))
+        );
+        assert_eq!(packer.insert(UVec2::new(1, 1)), None);
+    }
+
+    #[test]
+    fn failed_insert_leaves_packer_unchanged() {
+        let mut packer = RectPacker::new(UVec2::new(10, 10));
+        packer.insert(UVec2::new(6, 6)).unwrap();
+        let skyline = packer.skyline.clone();
+        assert_eq!(packer.insert(UVec2::new(5, 5)), None);
+        assert_eq!(packer.skyline, skyline);
+        assert_eq!(
+            packer.insert(UVec2::new(4, 10)),
+            Some(URect::new(6, 0, 10, 10))
+        );
+    }
+
+    #[test]
+    fn zero_sized() {
+        let mut packer = RectPacker::new(UVec2::new(4, 4));
+        assert_eq!(
+            packer.insert(UVec2::new(0, 3)),
+            Some(URect::new(0, 0, 0, 3))
+        );
+        assert_eq!(
+            packer.insert(UVec2::new(4, 4)),
+            Some(URect::new(0, 0, 4, 4))
+        );
+        assert_eq!(packer.insert(UVec2::ZERO), Some(URect::default()));
+        assert_eq!(packer.insert(UVec2::new(0, 5)), None);
+
+        assert_eq!(pack_rects(&[], UVec2::ZERO), Some(vec![]));
+    }
+
+    #[test]
+    fn padding_at_borders() {
+        // Padding is only kept between rects, so two 5-wide rects with a padding of 2 need 12.
+        let mut packer = RectPacker::new(UVec2::new(12, 5)).with_padding(2);
+        assert_eq!(
+            packer.insert(UVec2::new(5, 5)),
+            Some(URect::new(0, 0, 5, 5))
+        );
+        assert_eq!(
+            packer.insert(UVec2::new(5, 5)),
+            Some(URect::new(7, 0, 12, 5))
+        );
+        assert_eq!(packer.insert(UVec2::new(1, 1)), None);
+    }
+
+    #[test]
+    fn deterministic() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let sizes: Vec<UVec2> = (0..200)
+            .map(|_| UVec2::new(rng.gen_range(1..=16), rng.gen_range(1..=16)))
+            .collect();
+        let a = pack_rects(&sizes, UVec2::splat(256));
+        let b = pack_rects(&sizes, UVec2::splat(256));
+        assert!(a.is_some());
+        assert_eq!(a, b);
+    }
+}
@@ -321,6 +321,41 @@ impl IRect {
         r
     }
 
+    /// Returns an iterator over a grid of `columns` by `rows` tiles covering this rectangle, in
+    /// row-major order starting from the minimum corner.
+    ///
+    /// Adjacent tiles share their edges exactly. When the size isn't divisible by the number of
+    /// tiles, the tiles differ in size by at most one, with the remainder distributed evenly
+    /// across the grid. The iterator is empty if `columns` or `rows` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_math::IRect;
+    /// let r = IRect::new(0, 0, 10, 4);
+    /// let widths: Vec<i32> = r.tiles(4, 1).map(|tile| tile.width()).collect();
+    /// assert_eq!(widths, [2, 3, 2, 3]);
+    /// ```
+    pub fn tiles(&self, columns: u32, rows: u32) -> impl Iterator<Item = Self> {
+        let r = *self;
+        let edge = move |min: i32, size: i32, i: u32, count: u32| {
+            (min as i64 + size as i64 * i as i64 / count as i64) as i32
+        };
+        let size = r.size();
+        (0..rows).flat_map(move |row| {
+            (0..columns).map(move |column| Self {
+                min: IVec2::new(
+                    edge(r.min.x, size.x, column, columns),
+                    edge(r.min.y, size.y, row, rows),
+                ),
+                max: IVec2::new(
+                    edge(r.min.x, size.x, column + 1, columns),
+                    edge(r.min.y, size.y, row + 1, rows),
+                ),
+            })
+        })
+    }
+
     /// Returns self as [`Rect`] (f32)
     #[inline]
     pub fn as_rect(&self) -> Rect {
@@ -328,9 +363,23 @@ impl IRect {
     }
 
     /// Returns self as [`URect`] (u32)
+    ///
+    /// Negative coordinates are clamped to zero, so the result is the part of this rectangle
+    /// that lies in the positive quadrant, or an empty rectangle at the origin if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_math::{IRect, URect};
+    /// let r = IRect::new(-2, 1, 3, 4);
+    /// assert_eq!(r.as_urect(), URect::new(0, 1, 3, 4));
+    /// ```
     #[inline]
     pub fn as_urect(&self) -> URect {
-        URect::from_corners(self.min.as_uvec2(), self.max.as_uvec2())
+        URect::from_corners(
+            self.min.max(IVec2::ZERO).as_uvec2(),
+            self.max.max(IVec2::ZERO).as_uvec2(),
+        )
     }
 }
 
@@ -454,5 +503,55 @@ mod tests {
         let r2 = r.inset(2);
         assert_eq!(r2.min, IVec2::new(-4, -4));
         assert_eq!(r2.max, IVec2::new(4, 4));
+
+        // shrinking past zero size collapses instead of inverting
+        let r2 = r.inset(-3);
+        assert!(r2.is_empty());
+        assert_eq!(r2.size(), IVec2::ZERO);
+    }
+
+    #[test]
+    fn rect_casts() {
+        let r = IRect::new(-3, -2, 4, 5);
+        assert_eq!(r.as_rect(), Rect::new(-3., -2., 4., 5.));
+        assert_eq!(r.as_urect(), URect::new(0, 0, 4, 5));
+
+        // Entirely negative rects collapse to the origin.
+        assert_eq!(IRect::new(-5, -5, -1, -1).as_urect(), URect::default());
+        let r = IRect::new(i32::MIN, 2, -1, i32::MAX);
+        assert_eq!(r.as_urect(), URect::new(0, 2, 0, i32::MAX as u32));
+    }
+
+    #[test]
+    fn rect_tiles() {
+        let r = IRect::new(-7, 3, 10, 11); // w=17 h=8
+        let (columns, rows) = (5, 3);
+        let tiles: Vec<IRect> = r.tiles(columns, rows).collect();
+        assert_eq!(tiles.len(), 15);
+
+        let union = tiles
+            .iter()
+            .fold(tiles[0], |union, tile| union.union(*tile));
+        assert_eq!(union, r);
+        let area: i64 = tiles
+            .iter()
+            .map(|t| t.width() as i64 * t.height() as i64)
+            .sum();
+        assert_eq!(area, r.width() as i64 * r.height() as i64);
+        for (i, tile) in tiles.iter().enumerate() {
+            let (column, row) = (i as u32 % columns, i as u32 / columns);
+            if column + 1 < columns {
+                assert_eq!(tile.max.x, tiles[i + 1].min.x);
+            }
+            if row + 1 < rows {
+                assert_eq!(tile.max.y, tiles[i + columns as usize].min.y);
+            }
+            // The remainder is spread out, so sizes differ by at most one.
+            assert!(tile.width() == 3 || tile.width() == 4);
+            assert!(tile.height() == 2 || tile.height() == 3);
+        }
+
+        assert_eq!(r.tiles(3, 0).count(), 0);
+        assert_eq!(r.tiles(1, 1).collect::<Vec<_>>(), [r]);
     }
 }
@@ -19,6 +19,31 @@ pub struct Rect {
 }
 
 impl Rect {
+    /// An empty rectangle, with its minimum corner at positive infinity and its maximum corner
+    /// at negative infinity.
+    ///
+    /// This is the identity element of [`Rect::union()`] and [`Rect::union_point()`], which
+    /// makes it the natural starting value when accumulating the bounds of several rectangles
+    /// or points. Unlike starting from [`Rect::default()`], it does not spuriously include the
+    /// origin.
+    ///
+    /// It contains no point, [`Rect::is_empty()`] returns `true` and its size is zero.
+    /// Its center is not a finite point, so check [`Rect::is_empty()`] before using it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_math::{Rect, Vec2};
+    /// let rects = [Rect::new(1., 1., 2., 3.), Rect::new(4., 2., 5., 6.)];
+    /// let bounds = rects.iter().fold(Rect::EMPTY, |acc, r| acc.union(*r));
+    /// assert_eq!(bounds, Rect::new(1., 1., 5., 6.));
+    /// assert!(!bounds.contains(Vec2::ZERO));
+    /// ```
+    pub const EMPTY: Self = Self {
+        min: Vec2::INFINITY,
+        max: Vec2::NEG_INFINITY,
+    };
+
     /// Create a new rectangle from two corner points.
     ///
     /// The two points do not need to be the minimum and/or maximum corners.
@@ -105,14 +130,38 @@ impl Rect {
         }
     }
 
+    /// Create the smallest rectangle enclosing all the given points.
+    ///
+    /// Returns `None` if `points` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_math::{Rect, Vec2};
+    /// let r = Rect::from_points([Vec2::new(1., 5.), Vec2::new(3., -1.), Vec2::new(2., 2.)]);
+    /// assert_eq!(r, Some(Rect::new(1., -1., 3., 5.)));
+    ///
+    /// assert_eq!(Rect::from_points([]), None);
+    /// ```
+    #[inline]
+    pub fn from_points(points: impl IntoIterator<Item = Vec2>) -> Option<Self> {
+        let r = points
+            .into_iter()
+            .fold(Self::EMPTY, |r, point| r.union_point(point));
+        (r != Self::EMPTY).then_some(r)
+    }
+
     /// Check if the rectangle is empty.
     ///
+    /// This is the case for rectangles with a zero width or height, and for [`Rect::EMPTY`].
+    ///
     /// # Examples
     ///
     /// ```rust
     /// # use bevy_math::{Rect, Vec2};
     /// let r = Rect::from_corners(Vec2::ZERO, Vec2::new(0., 1.)); // w=0 h=1
     /// assert!(r.is_empty());
+    /// assert!(Rect::EMPTY.is_empty());
     /// ```
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -121,6 +170,8 @@ impl Rect {
 
     /// Rectangle width (max.x - min.x).
     ///
+    /// The width is never negative, in particular it is zero for [`Rect::EMPTY`].
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -130,11 +181,13 @@ impl Rect {
     /// ```
     #[inline]
     pub fn width(&self) -> f32 {
-        self.max.x - self.min.x
+        (self.max.x - self.min.x).max(0.)
     }
 
     /// Rectangle height (max.y - min.y).
     ///
+    /// The height is never negative, in particular it is zero for [`Rect::EMPTY`].
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -144,11 +197,13 @@ impl Rect {
     /// ```
     #[inline]
     pub fn height(&self) -> f32 {
-        self.max.y - self.min.y
+        (self.max.y - self.min.y).max(0.)
     }
 
     /// Rectangle size.
     ///
+    /// The size is never negative, in particular it is zero for [`Rect::EMPTY`].
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -158,7 +213,7 @@ impl Rect {
     /// ```
     #[inline]
     pub fn size(&self) -> Vec2 {
-        self.max - self.min
+        (self.max - self.min).max(Vec2::ZERO)
     }
 
     /// Rectangle half-size.
@@ -207,7 +262,8 @@ impl Rect {
 
     /// Build a new rectangle formed of the union of this rectangle and another rectangle.
     ///
-    /// The union is the smallest rectangle enclosing both rectangles.
+    /// The union is the smallest rectangle enclosing both rectangles. [`Rect::EMPTY`] is the
+    /// identity element: the union of any rectangle with it is that rectangle.
     ///
     /// # Examples
     ///
@@ -230,7 +286,8 @@ impl Rect {
     /// Build a new rectangle formed of the union of this rectangle and a point.
     ///
     /// The union is the smallest rectangle enclosing both the rectangle and the point. If the
-    /// point is already inside the rectangle, this method returns a copy of the rectangle.
+    /// point is already inside the rectangle, this method returns a copy of the rectangle. The
+    /// union of [`Rect::EMPTY`] and a point is the zero-sized rectangle at that point.
     ///
     /// # Examples
     ///
@@ -253,7 +310,8 @@ impl Rect {
     ///
     /// The intersection is the largest rectangle enclosed in both rectangles. If the intersection
     /// is empty, this method returns an empty rectangle ([`Rect::is_empty()`] returns `true`), but
-    /// the actual values of [`Rect::min`] and [`Rect::max`] are implementation-dependent.
+    /// the actual values of [`Rect::min`] and [`Rect::max`] are implementation-dependent. The
+    /// intersection of any rectangle with [`Rect::EMPTY`] is [`Rect::EMPTY`].
     ///
     /// # Examples
     ///
@@ -267,6 +325,9 @@ impl Rect {
     /// ```
     #[inline]
     pub fn intersect(&self, other: Self) -> Self {
+        if *self == Self::EMPTY || other == Self::EMPTY {
+            return Self::EMPTY;
+        }
         let mut r = Self {
             min: self.min.max(other.min),
             max: self.max.min(other.max),
@@ -282,6 +343,7 @@ impl Rect {
     /// The inset is the extra border on all sides. A positive inset produces a larger rectangle,
     /// while a negative inset is allowed and produces a smaller rectangle. If the inset is negative
     /// and its absolute value is larger than the rectangle half-size, the created rectangle is empty.
+    /// Insetting [`Rect::EMPTY`] returns [`Rect::EMPTY`].
     ///
     /// # Examples
     ///
@@ -299,6 +361,9 @@ impl Rect {
     /// ```
     #[inline]
     pub fn inset(&self, inset: f32) -> Self {
+        if *self == Self::EMPTY {
+            return Self::EMPTY;
+        }
         let mut r = Self {
             min: self.min - inset,
             max: self.max + inset,
@@ -309,16 +374,169 @@ impl Rect {
         r
     }
 
+    /// Split this rectangle into a left part of the given width and the remaining right part.
+    ///
+    /// The width is clamped to the width of this rectangle, and both parts share their edge
+    /// exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_math::Rect;
+    /// let r = Rect::new(0., 0., 5., 2.);
+    /// let (left, right) = r.split_left(1.5);
+    /// assert_eq!(left, Rect::new(0., 0., 1.5, 2.));
+    /// assert_eq!(right, Rect::new(1.5, 0., 5., 2.));
+    /// ```
+    #[inline]
+    pub fn split_left(&self, width: f32) -> (Self, Self) {
+        let x = (self.min.x + width).clamp(self.min.x, self.max.x);
+        (
+            Self::from_corners(self.min, Vec2::new(x, self.max.y)),
+            Self::from_corners(Vec2::new(x, self.min.y), self.max),
+        )
+    }
+
+    /// Split this rectangle into a top part of the given height and the remaining bottom part.
+    ///
+    /// As in UI layout, the top is the side with the smaller y coordinate. The height is clamped
+    /// to the height of this rectangle, and both parts share their edge exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_math::Rect;
+    /// let r = Rect::new(0., 0., 5., 2.);
+    /// let (top, bottom) = r.split_top(0.5);
+    /// assert_eq!(top, Rect::new(0., 0., 5., 0.5));
+    /// assert_eq!(bottom, Rect::new(0., 0.5, 5., 2.));
+    /// ```
+    #[inline]
+    pub fn split_top(&self, height: f32) -> (Self, Self) {
+        let y = (self.min.y + height).clamp(self.min.y, self.max.y);
+        (
+            Self::from_corners(self.min, Vec2::new(self.max.x, y)),
+            Self::from_corners(Vec2::new(self.min.x, y), self.max),
+        )
+    }
+
+    /// Split this rectangle into a left and right part, where the left part takes up the given
+    /// fraction of the width.
+    ///
+    /// The fraction is clamped to `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_math::Rect;
+    /// let r = Rect::new(0., 0., 4., 2.);
+    /// let (left, right) = r.split_at_fraction_x(0.25);
+    /// assert_eq!(left, Rect::new(0., 0., 1., 2.));
+    /// assert_eq!(right, Rect::new(1., 0., 4., 2.));
+    /// ```
+    #[inline]
+    pub fn split_at_fraction_x(&self, fraction: f32) -> (Self, Self) {
+        self.split_left(self.width() * fraction.clamp(0., 1.))
+    }
+
+    /// Split this rectangle into a top and bottom part, where the top part takes up the given
+    /// fraction of the height.
+    ///
+    /// As in UI layout, the top is the side with the smaller y coordinate. The fraction is clamped
+    /// to `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_math::Rect;
+    /// let r = Rect::new(0., 0., 4., 2.);
+    /// let (top, bottom) = r.split_at_fraction_y(0.25);
+    /// assert_eq!(top, Rect::new(0., 0., 4., 0.5));
+    /// assert_eq!(bottom, Rect::new(0., 0.5, 4., 2.));
+    /// ```
+    #[inline]
+    pub fn split_at_fraction_y(&self, fraction: f32) -> (Self, Self) {
+        self.split_top(self.height() * fraction.clamp(0., 1.))
+    }
+
+    /// Returns an iterator over a grid of `columns` by `rows` tiles covering this rectangle, in
+    /// row-major order starting from the minimum corner.
+    ///
+    /// Adjacent tiles share their edges exactly and the outer edges of the grid are the edges of
+    /// this rectangle, so there are no gaps due to rounding. The iterator is empty if `columns` or
+    /// `rows` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_math::Rect;
+    /// let r = Rect::new(0., 0., 4., 2.);
+    /// let tiles: Vec<Rect> = r.tiles(2, 1).collect();
+    /// assert_eq!(tiles, [Rect::new(0., 0., 2., 2.), Rect::new(2., 0., 4., 2.)]);
+    /// ```
+    pub fn tiles(&self, columns: u32, rows: u32) -> impl Iterator<Item = Self> {
+        let r = *self;
+        let edge = move |i: u32, count: u32| {
+            if i == count {
+                r.max
+            } else {
+                r.min + r.size() * Vec2::splat(i as f32 / count as f32)
+            }
+        };
+        (0..rows).flat_map(move |row| {
+            (0..columns).map(move |column| Self {
+                min: Vec2::new(edge(column, columns).x, edge(row, rows).y),
+                max: Vec2::new(edge(column + 1, columns).x, edge(row + 1, rows).y),
+            })
+        })
+    }
+
     /// Returns self as [`IRect`] (i32)
+    ///
+    /// The minimum corner is rounded down and the maximum corner is rounded up, so that the
+    /// integer rectangle covers this rectangle. Coordinates outside the range of `i32` saturate.
+    ///
+    /// Empty rectangles, including [`Rect::EMPTY`] and rectangles with `min > max`, cover nothing
+    /// and give an empty rectangle at the origin.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_math::{IRect, Rect};
+    /// let r = Rect::new(-1.5, 0.2, 2.5, 3.0);
+    /// assert_eq!(r.as_irect(), IRect::new(-2, 0, 3, 3));
+    /// ```
     #[inline]
     pub fn as_irect(&self) -> IRect {
-        IRect::from_corners(self.min.as_ivec2(), self.max.as_ivec2())
+        // `from_corners` would swap the saturated corners of an inverted rect, covering everything.
+        if self.is_empty() {
+            return IRect::default();
+        }
+        IRect::from_corners(self.min.floor().as_ivec2(), self.max.ceil().as_ivec2())
     }
 
     /// Returns self as [`URect`] (u32)
+    ///
+    /// The minimum corner is rounded down and the maximum corner is rounded up, so that the
+    /// integer rectangle covers this rectangle. Negative coordinates are clamped to zero and
+    /// coordinates above the range of `u32` saturate.
+    ///
+    /// Empty rectangles, including [`Rect::EMPTY`] and rectangles with `min > max`, cover nothing
+    /// and give an empty rectangle at the origin.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_math::{Rect, URect};
+    /// let r = Rect::new(-1.5, 0.2, 2.5, 3.0);
+    /// assert_eq!(r.as_urect(), URect::new(0, 0, 3, 3));
+    /// ```
     #[inline]
     pub fn as_urect(&self) -> URect {
-        URect::from_corners(self.min.as_uvec2(), self.max.as_uvec2())
+        if self.is_empty() {
+            return URect::default();
+        }
+        URect::from_corners(self.min.floor().as_uvec2(), self.max.ceil().as_uvec2())
     }
 }
 
@@ -442,5 +660,156 @@ mod tests {
         let r2 = r.inset(0.3);
         assert!(r2.min.abs_diff_eq(Vec2::new(-0.8, -0.8), 1e-5));
         assert!(r2.max.abs_diff_eq(Vec2::new(0.8, 0.8), 1e-5));
+
+        // shrinking past zero size collapses instead of inverting
+        let r2 = r.inset(-2.);
+        assert!(r2.is_empty());
+        assert!(r2.min.cmple(r2.max).all());
+        assert_eq!(r2.size(), Vec2::ZERO);
+    }
+
+    #[test]
+    fn rect_empty() {
+        let e = Rect::EMPTY;
+
+        assert!(e.is_empty());
+        assert_eq!(e.width(), 0.);
+        assert_eq!(e.height(), 0.);
+        assert_eq!(e.size(), Vec2::ZERO);
+        assert_eq!(e.half_size(), Vec2::ZERO);
+        assert!(!e.contains(Vec2::ZERO));
+        assert!(!e.contains(Vec2::INFINITY));
+        assert!(!e.contains(Vec2::NEG_INFINITY));
+
+        assert_eq!(e.union(e), e);
+        assert_eq!(e.intersect(e), e);
+        assert_eq!(e.inset(2.), e);
+        assert_eq!(e.inset(-2.), e);
+
+        // Identity element of union
+        let r = Rect::new(1., 2., 3., 4.);
+        assert_eq!(e.union(r), r);
+        assert_eq!(r.union(e), r);
+        assert_eq!(
+            e.union_point(Vec2::new(5., -1.)),
+            Rect::new(5., -1., 5., -1.)
+        );
+
+        // Absorbing element of intersection
+        assert_eq!(e.intersect(r), e);
+        assert_eq!(r.intersect(e), e);
+    }
+
+    #[test]
+    fn rect_empty_fold() {
+        let rects = [
+            Rect::new(1., 1., 2., 2.),
+            Rect::new(3., 0.5, 4., 5.),
+            Rect::new(-1., 10., -2., 11.),
+        ];
+        let u = rects.iter().fold(Rect::EMPTY, |acc, r| acc.union(*r));
+        assert_eq!(u, Rect::new(-2., 0.5, 4., 11.));
+        assert!(!u.contains(Vec2::ZERO));
+
+        // Folding from the default rect spuriously includes the origin
+        let u = rects.iter().fold(Rect::default(), |acc, r| acc.union(*r));
+        assert!(u.contains(Vec2::ZERO));
+
+        // Intersecting with the empty rect doesn't leak into the union either
+        let e = rects[0].intersect(Rect::EMPTY);
+        assert_eq!(e.union(rects[1]), rects[1]);
+    }
+
+    #[test]
+    fn rect_from_points() {
+        assert_eq!(Rect::from_points([]), None);
+        assert_eq!(
+            Rect::from_points([Vec2::new(2., 3.)]),
+            Some(Rect::new(2., 3., 2., 3.))
+        );
+        assert_eq!(
+            Rect::from_points([Vec2::new(2., 3.), Vec2::new(4., 1.), Vec2::new(3., 7.)]),
+            Some(Rect::new(2., 1., 4., 7.))
+        );
+    }
+
+    #[test]
+    fn rect_casts() {
+        // Integer rects cover the float rect, also for negative coordinates.
+        let r = Rect::new(-1.5, -0.5, 2.25, 3.);
+        assert_eq!(r.as_irect(), IRect::new(-2, -1, 3, 3));
+        assert_eq!(r.as_urect(), URect::new(0, 0, 3, 3));
+
+        // Out of range coordinates saturate.
+        let r = Rect::new(-1e20, -1e20, 1e20, 1e20);
+        assert_eq!(
+            r.as_irect(),
+            IRect::new(i32::MIN, i32::MIN, i32::MAX, i32::MAX)
+        );
+        assert_eq!(r.as_urect(), URect::new(0, 0, u32::MAX, u32::MAX));
+
+        // Entirely negative rects collapse to the origin.
+        assert_eq!(Rect::new(-3., -3., -1., -1.).as_urect(), URect::default());
+
+        // Empty and inverted rects stay empty instead of saturating to cover everything.
+        assert_eq!(Rect::EMPTY.as_irect(), IRect::default());
+        assert_eq!(Rect::EMPTY.as_urect(), URect::default());
+        let inverted = Rect {
+            min: Vec2::new(5., 2.),
+            max: Vec2::new(1., 4.),
+        };
+        assert!(inverted.as_irect().is_empty());
+        assert!(inverted.as_urect().is_empty());
+        let empty_union = [].iter().fold(Rect::EMPTY, |acc, r: &Rect| acc.union(*r));
+        assert!(empty_union.as_irect().is_empty());
+    }
+
+    #[test]
+    fn rect_split() {
+        let r = Rect::new(-1., -2., 2., 1.3);
+
+        let (left, right) = r.split_left(0.7);
+        assert_eq!(left.max.x, right.min.x);
+        assert_eq!(left.union(right), r);
+        // Clamped to the rect
+        assert_eq!(r.split_left(10.), (r, Rect::new(2., -2., 2., 1.3)));
+        assert_eq!(r.split_left(-1.).0.width(), 0.);
+
+        let (top, bottom) = r.split_top(1.1);
+        assert_eq!(top.max.y, bottom.min.y);
+        assert_eq!(top.union(bottom), r);
+
+        let (left, right) = r.split_at_fraction_x(1. / 3.);
+        assert!((left.width() - 1.).abs() < 1e-5);
+        assert_eq!(left.union(right), r);
+        let (top, bottom) = r.split_at_fraction_y(2.);
+        assert_eq!((top, bottom.height()), (r, 0.));
+    }
+
+    #[test]
+    fn rect_tiles() {
+        let r = Rect::new(-1.1, 0.3, 2.9, 1.7);
+        let (columns, rows) = (7, 3);
+        let tiles: Vec<Rect> = r.tiles(columns, rows).collect();
+        assert_eq!(tiles.len(), 21);
+
+        let union = tiles
+            .iter()
+            .fold(Rect::EMPTY, |union, tile| union.union(*tile));
+        assert_eq!(union, r);
+        for (i, tile) in tiles.iter().enumerate() {
+            let (column, row) = (i as u32 % columns, i as u32 / columns);
+            if column + 1 < columns {
+                assert_eq!(tile.max.x, tiles[i + 1].min.x);
+                assert_eq!(tile.min.y, tiles[i + 1].min.y);
+            }
+            if row + 1 < rows {
+                assert_eq!(tile.max.y, tiles[i + columns as usize].min.y);
+            }
+            assert!((tile.width() - r.width() / columns as f32).abs() < 1e-5);
+        }
+
+        assert_eq!(r.tiles(0, 3).count(), 0);
+        assert_eq!(r.tiles(1, 1).collect::<Vec<_>>(), [r]);
     }
 }
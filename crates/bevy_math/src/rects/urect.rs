@@ -324,6 +324,41 @@ impl URect {
         r
     }
 
+    /// Returns an iterator over a grid of `columns` by `rows` tiles covering this rectangle, in
+    /// row-major order starting from the minimum corner.
+    ///
+    /// Adjacent tiles share their edges exactly. When the size isn't divisible by the number of
+    /// tiles, the tiles differ in size by at most one, with the remainder distributed evenly
+    /// across the grid. The iterator is empty if `columns` or `rows` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_math::URect;
+    /// let r = URect::new(0, 0, 10, 4);
+    /// let widths: Vec<u32> = r.tiles(4, 1).map(|tile| tile.width()).collect();
+    /// assert_eq!(widths, [2, 3, 2, 3]);
+    /// ```
+    pub fn tiles(&self, columns: u32, rows: u32) -> impl Iterator<Item = Self> {
+        let r = *self;
+        let edge = move |min: u32, size: u32, i: u32, count: u32| {
+            (min as u64 + size as u64 * i as u64 / count as u64) as u32
+        };
+        let size = r.size();
+        (0..rows).flat_map(move |row| {
+            (0..columns).map(move |column| Self {
+                min: UVec2::new(
+                    edge(r.min.x, size.x, column, columns),
+                    edge(r.min.y, size.y, row, rows),
+                ),
+                max: UVec2::new(
+                    edge(r.min.x, size.x, column + 1, columns),
+                    edge(r.min.y, size.y, row + 1, rows),
+                ),
+            })
+        })
+    }
+
     /// Returns self as [`Rect`] (f32)
     #[inline]
     pub fn as_rect(&self) -> Rect {
@@ -331,9 +366,20 @@ impl URect {
     }
 
     /// Returns self as [`IRect`] (i32)
+    ///
+    /// Coordinates above [`i32::MAX`] are clamped to it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_math::{IRect, URect};
+    /// let r = URect::new(1, 2, u32::MAX, 4);
+    /// assert_eq!(r.as_irect(), IRect::new(1, 2, i32::MAX, 4));
+    /// ```
     #[inline]
     pub fn as_irect(&self) -> IRect {
-        IRect::from_corners(self.min.as_ivec2(), self.max.as_ivec2())
+        let max = UVec2::splat(i32::MAX as u32);
+        IRect::from_corners(self.min.min(max).as_ivec2(), self.max.min(max).as_ivec2())
     }
 }
 
@@ -457,5 +503,59 @@ mod tests {
         let r2 = r.inset(2);
         assert_eq!(r2.min, UVec2::new(1, 1));
         assert_eq!(r2.max, UVec2::new(11, 11));
+
+        // growing saturates at zero
+        let r2 = r.inset(5);
+        assert_eq!(r2.min, UVec2::ZERO);
+        assert_eq!(r2.max, UVec2::new(14, 14));
+
+        // shrinking past zero size collapses instead of inverting
+        let r2 = r.inset(-4);
+        assert!(r2.is_empty());
+        assert_eq!(r2.size(), UVec2::ZERO);
+    }
+
+    #[test]
+    fn rect_casts() {
+        let r = URect::new(1, 2, 3, 4);
+        assert_eq!(r.as_rect(), Rect::new(1., 2., 3., 4.));
+        assert_eq!(r.as_irect(), IRect::new(1, 2, 3, 4));
+
+        // Coordinates above i32::MAX are clamped instead of wrapping.
+        let r = URect::new(0, u32::MAX, u32::MAX, i32::MAX as u32 + 1);
+        assert_eq!(r.as_irect(), IRect::new(0, i32::MAX, i32::MAX, i32::MAX));
+    }
+
+    #[test]
+    fn rect_tiles() {
+        let r = URect::new(3, 5, 20, 13); // w=17 h=8
+        let (columns, rows) = (5, 3);
+        let tiles: Vec<URect> = r.tiles(columns, rows).collect();
+        assert_eq!(tiles.len(), 15);
+
+        let union = tiles
+            .iter()
+            .fold(tiles[0], |union, tile| union.union(*tile));
+        assert_eq!(union, r);
+        let area: i64 = tiles
+            .iter()
+            .map(|t| t.width() as i64 * t.height() as i64)
+            .sum();
+        assert_eq!(area, r.width() as i64 * r.height() as i64);
+        for (i, tile) in tiles.iter().enumerate() {
+            let (column, row) = (i as u32 % columns, i as u32 / columns);
+            if column + 1 < columns {
+                assert_eq!(tile.max.x, tiles[i + 1].min.x);
+            }
+            if row + 1 < rows {
+                assert_eq!(tile.max.y, tiles[i + columns as usize].min.y);
+            }
+            // The remainder is spread out, so sizes differ by at most one.
+            assert!(tile.width() == 3 || tile.width() == 4);
+            assert!(tile.height() == 2 || tile.height() == 3);
+        }
+
+        assert_eq!(r.tiles(3, 0).count(), 0);
+        assert_eq!(r.tiles(1, 1).collect::<Vec<_>>(), [r]);
     }
 }
@@ -0,0 +1,386 @@
+//! Frame-rate independent smoothing of values towards a moving target.
+
+use std::{
+    f32::consts::{PI, TAU},
+    ops::{Add, Mul, Sub},
+};
+
+use crate::{Quat, Vec2, Vec3, Vec3A};
+
+/// Values that can be smoothly moved towards a target with a critically damped spring.
+///
+/// This is commonly used for cameras and other objects that follow a target, as it
+/// accelerates and decelerates smoothly without overshooting. The result only depends on the
+/// elapsed time, not on how it is split into frames, up to a small approximation error.
+///
+/// # Examples
+///
+/// ```rust
+/// # use bevy_math::{SmoothDamp, Vec3};
+/// let target = Vec3::new(10.0, 0.0, 0.0);
+/// let mut position = Vec3::ZERO;
+/// let mut velocity = Vec3::ZERO;
+/// for _ in 0..600 {
+///     // The velocity has to be kept around between frames.
+///     position = position.smooth_damp(target, &mut velocity, 0.3, f32::INFINITY, 1.0 / 60.0);
+/// }
+/// assert!(position.distance(target) < 1e-3);
+/// ```
+pub trait SmoothDamp: Sized {
+    /// Move `self` towards `target`, returning the new value and updating `velocity`.
+    ///
+    /// - `velocity` is the current rate of change, which must be kept between calls and is
+    ///   usually initialized to zero.
+    /// - `smooth_time` is roughly the time in seconds it takes to reach the target. Smaller
+    ///   values reach the target faster.
+    /// - `max_speed` limits how fast the value may move. Use [`f32::INFINITY`] for no limit.
+    /// - `delta` is the time in seconds since the last call.
+    ///
+    /// The value never overshoots the target, and it stays exactly at the target once it has
+    /// reached it with zero velocity.
+    fn smooth_damp(
+        self,
+        target: Self,
+        velocity: &mut Self,
+        smooth_time: f32,
+        max_speed: f32,
+        delta: f32,
+    ) -> Self;
+}
+
+/// The vector operations needed by [`smooth_damp`].
+trait DampVector: Copy + Add<Output = Self> + Sub<Output = Self> + Mul<f32, Output = Self> {
+    const ZERO: Self;
+
+    fn length(self) -> f32;
+
+    fn dot(self, rhs: Self) -> f32;
+}
+
+impl DampVector for f32 {
+    const ZERO: Self = 0.0;
+
+    #[inline]
+    fn length(self) -> f32 {
+        self.abs()
+    }
+
+    #[inline]
+    fn dot(self, rhs: Self) -> f32 {
+        self * rhs
+    }
+}
+
+macro_rules! impl_smooth_damp {
+    ($($vec:ty),*) => {$(
+        impl DampVector for $vec {
+            const ZERO: Self = <$vec>::ZERO;
+
+            #[inline]
+            fn length(self) -> f32 {
+                <$vec>::length(self)
+            }
+
+            #[inline]
+            fn dot(self, rhs: Self) -> f32 {
+                <$vec>::dot(self, rhs)
+            }
+        }
+    )*
+
+    $(
+        impl SmoothDamp for $vec {
+            #[inline]
+            fn smooth_damp(
+                self,
+                target: Self,
+                velocity: &mut Self,
+                smooth_time: f32,
+                max_speed: f32,
+                delta: f32,
+            ) -> Self {
+                smooth_damp(self, target, velocity, smooth_time, max_speed, delta)
+            }
+        }
+    )*};
+}
+
+impl_smooth_damp!(Vec2, Vec3, Vec3A);
+
+impl SmoothDamp for f32 {
+    #[inline]
+    fn smooth_damp(
+        self,
+        target: Self,
+        velocity: &mut Self,
+        smooth_time: f32,
+        max_speed: f32,
+        delta: f32,
+    ) -> Self {
+        smooth_damp(self, target, velocity, smooth_time, max_speed, delta)
+    }
+}
+
+/// The critically damped spring shared by all [`SmoothDamp`] implementations.
+///
+/// This uses the stable closed-form approximation from Game Programming Gems 4, chapter 1.10,
+/// which is also used by most game engines.
+#[inline]
+fn smooth_damp<V: DampVector>(
+    current: V,
+    target: V,
+    velocity: &mut V,
+    smooth_time: f32,
+    max_speed: f32,
+    delta: f32,
+) -> V {
+    let smooth_time = smooth_time.max(1e-4);
+    let omega = 2.0 / smooth_time;
+    let x = omega * delta;
+    // A Taylor approximation of `exp(-x)` that stays stable for large time steps.
+    let decay = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+
+    // Limit the distance to the target, which limits the speed of the spring.
+    let mut change = current - target;
+    let max_change = max_speed * smooth_time;
+    let distance = change.length();
+    if distance > max_change {
+        change = change * (max_change / distance);
+    }
+    let clamped_target = current - change;
+
+    let temp = (*velocity + change * omega) * delta;
+    *velocity = (*velocity - temp * omega) * decay;
+    let output = clamped_target + (change + temp) * decay;
+
+    // Stop at the target instead of overshooting it.
+    if (target - current).dot(output - target) > 0.0 {
+        *velocity = V::ZERO;
+        return target;
+    }
+    output
+}
+
+/// Smoothly move the angle `current` towards `target` along the shortest way around the
+/// circle, both in radians, using a critically damped spring.
+///
+/// `velocity` is the angular velocity in radians per second, and `max_speed` limits it.
+/// See [`SmoothDamp::smooth_damp`] for the other parameters.
+///
+/// The returned angle is not wrapped, so it may leave the `-PI..=PI` range.
+///
+/// # Examples
+///
+/// ```rust
+/// # use bevy_math::smooth_damp_angle;
+/// # use std::f32::consts::PI;
+/// let mut angle = 0.9 * PI;
+/// let mut velocity = 0.0;
+/// for _ in 0..600 {
+///     angle = smooth_damp_angle(angle, -0.9 * PI, &mut velocity, 0.3, f32::INFINITY, 1.0 / 60.0);
+/// }
+/// // Rotated through `PI` instead of back through zero.
+/// assert!((angle - 1.1 * PI).abs() < 1e-3);
+/// ```
+#[inline]
+pub fn smooth_damp_angle(
+    current: f32,
+    target: f32,
+    velocity: &mut f32,
+    smooth_time: f32,
+    max_speed: f32,
+    delta: f32,
+) -> f32 {
+    let difference = (target - current + PI).rem_euclid(TAU) - PI;
+    current.smooth_damp(
+        current + difference,
+        velocity,
+        smooth_time,
+        max_speed,
+        delta,
+    )
+}
+
+/// Smoothly rotate `current` towards `target` along the shortest arc, using a critically
+/// damped spring.
+///
+/// `angular_velocity` is the rate at which the remaining rotation to `target` changes, as a
+/// scaled axis in radians per second, and `max_speed` limits its length.
+/// See [`SmoothDamp::smooth_damp`] for the other parameters.
+///
+/// # Examples
+///
+/// ```rust
+/// # use bevy_math::{smooth_damp_rotation, Quat, Vec3};
+/// let target = Quat::from_rotation_y(2.0);
+/// let mut rotation = Quat::IDENTITY;
+/// let mut angular_velocity = Vec3::ZERO;
+/// for _ in 0..600 {
+///     rotation = smooth_damp_rotation(
+///         rotation,
+///         target,
+///         &mut angular_velocity,
+///         0.3,
+///         f32::INFINITY,
+///         1.0 / 60.0,
+///     );
+/// }
+/// assert!(rotation.angle_between(target) < 1e-3);
+/// ```
+#[inline]
+pub fn smooth_damp_rotation(
+    current: Quat,
+    target: Quat,
+    angular_velocity: &mut Vec3,
+    smooth_time: f32,
+    max_speed: f32,
+    delta: f32,
+) -> Quat {
+    let offset = scaled_axis_between(current, target).smooth_damp(
+        Vec3::ZERO,
+        angular_velocity,
+        smooth_time,
+        max_speed,
+        delta,
+    );
+    Quat::from_scaled_axis(offset) * target
+}
+
+/// The rotation from `to` to `from` along the shortest arc, as a scaled axis.
+///
+/// Unlike [`Quat::to_scaled_axis`], this stays precise for small angles.
+#[inline]
+fn scaled_axis_between(from: Quat, to: Quat) -> Vec3 {
+    let mut offset = from * to.inverse();
+    if offset.w < 0.0 {
+        offset = -offset;
+    }
+    let sin_half_angle = offset.xyz().length();
+    if sin_half_angle <= 0.0 {
+        return Vec3::ZERO;
+    }
+    let angle = 2.0 * sin_half_angle.atan2(offset.w);
+    offset.xyz() * (angle / sin_half_angle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Time steps of an uneven frame rate, cycled through in simulations.
+    const DELTAS: [f32; 6] = [1. / 60., 1. / 144., 1. / 30., 0.1, 1. / 60., 0.004];
+
+    /// Runs `steps` frames of `smooth_damp` with uneven time steps, returning the values
+    /// and time steps of every frame.
+    fn simulate(
+        mut current: f32,
+        target: f32,
+        smooth_time: f32,
+        max_speed: f32,
+        steps: usize,
+    ) -> Vec<(f32, f32)> {
+        let mut velocity = 0.0;
+        DELTAS
+            .iter()
+            .cycle()
+            .take(steps)
+            .map(|&delta| {
+                current = current.smooth_damp(target, &mut velocity, smooth_time, max_speed, delta);
+                (current, delta)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn converges_without_overshoot() {
+        for (start, target) in [(0.0, 10.0), (5.0, -3.0), (-100.0, -99.0)] {
+            let frames = simulate(start, target, 0.25, f32::INFINITY, 1000);
+            let mut previous = start;
+            for &(value, _) in &frames {
+                // Monotonically approaches the target, never passing it.
+                assert!((target - value).abs() <= (target - previous).abs());
+                assert!((target - value).signum() * (target - start).signum() >= 0.0);
+                previous = value;
+            }
+            assert!((previous - target).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn respects_max_speed() {
+        let max_speed = 2.0;
+        let frames = simulate(0.0, 100.0, 0.5, max_speed, 2000);
+        let mut previous = 0.0;
+        for &(value, delta) in &frames {
+            assert!((value - previous) / delta <= max_speed * 1.001);
+            previous = value;
+        }
+        // Moves at close to the maximum speed while far from the target.
+        let elapsed: f32 = frames.iter().map(|(_, delta)| delta).sum();
+        assert!(previous > 0.9 * max_speed * elapsed.min(50.0));
+    }
+
+    #[test]
+    fn exact_at_target() {
+        let mut velocity = Vec3::ZERO;
+        let target = Vec3::new(1.5, -2.0, 0.25);
+        for delta in DELTAS {
+            let value = target.smooth_damp(target, &mut velocity, 0.3, 10.0, delta);
+            assert_eq!(value, target);
+            assert_eq!(velocity, Vec3::ZERO);
+        }
+
+        let mut angular_velocity = Vec3::ZERO;
+        let rotation = Quat::from_rotation_x(1.0);
+        let value = smooth_damp_rotation(rotation, rotation, &mut angular_velocity, 0.3, 1.0, 0.1);
+        assert_eq!(value, rotation);
+        assert_eq!(angular_velocity, Vec3::ZERO);
+    }
+
+    #[test]
+    fn frame_rate_independent() {
+        let run = |delta: f32, steps: usize| {
+            let mut value = Vec2::ZERO;
+            let mut velocity = Vec2::ZERO;
+            for _ in 0..steps {
+                value = value.smooth_damp(Vec2::ONE, &mut velocity, 0.5, f32::INFINITY, delta);
+            }
+            value
+        };
+        // Half a second, simulated at 30 and 240 frames per second.
+        let slow = run(1. / 30., 15);
+        let fast = run(1. / 240., 120);
+        assert!(slow.distance(fast) < 0.01);
+    }
+
+    #[test]
+    fn angle_takes_shortest_way() {
+        let mut angle = 3.0;
+        let mut velocity = 0.0;
+        let target = -3.0;
+        for delta in DELTAS.iter().cycle().take(600) {
+            let next = smooth_damp_angle(angle, target, &mut velocity, 0.2, f32::INFINITY, *delta);
+            // Increases towards `TAU - 3.0` instead of decreasing through zero.
+            assert!(next >= angle);
+            angle = next;
+        }
+        assert!((angle - (TAU - 3.0)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn rotation_converges() {
+        let target = Quat::from_euler(crate::EulerRot::XYZ, 0.5, -2.0, 1.0);
+        let mut rotation = Quat::from_rotation_z(3.0);
+        let mut angular_velocity = Vec3::ZERO;
+        let mut previous_angle = scaled_axis_between(rotation, target).length();
+        for delta in DELTAS.iter().cycle().take(600) {
+            rotation =
+                smooth_damp_rotation(rotation, target, &mut angular_velocity, 0.3, 5.0, *delta);
+            let angle = scaled_axis_between(rotation, target).length();
+            assert!(angle <= previous_angle + 1e-6);
+            previous_angle = angle;
+        }
+        assert!(previous_angle < 1e-4);
+        assert!(rotation.is_normalized());
+    }
+}
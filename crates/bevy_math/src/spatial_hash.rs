@@ -0,0 +1,328 @@
+//! A uniform grid for finding nearby objects, see [`SpatialHashGrid2d`].
+
+use std::collections::HashMap;
+
+use crate::{I64Vec2, IVec2, Rect, Vec2};
+
+/// A spatial hash over a uniform grid of square cells, for finding the values whose bounds are
+/// near a point or area.
+///
+/// This is simpler and faster than a hierarchical structure when the objects are of similar size,
+/// such as boids or particles. Pick a cell size around the size of a typical object: objects much
+/// larger than a cell are inserted into many cells, while much larger cells mean more objects per
+/// query to check.
+///
+/// The grid is meant to be rebuilt every frame: [`clear`](Self::clear) keeps the buckets of the
+/// cells that were in use around for reuse, and drops the others. Queries return values in the
+/// order they were inserted, which makes the results deterministic regardless of the cell layout.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_math::{spatial_hash::SpatialHashGrid2d, Rect, Vec2};
+/// let mut grid = SpatialHashGrid2d::new(10.0);
+/// grid.insert(Rect::from_center_size(Vec2::new(5.0, 5.0), Vec2::splat(2.0)), "near");
+/// grid.insert(Rect::from_center_size(Vec2::new(-50.0, 5.0), Vec2::splat(2.0)), "far");
+///
+/// let query = Rect::from_center_size(Vec2::ZERO, Vec2::splat(20.0));
+/// assert_eq!(grid.query_rect(query).collect::<Vec<_>>(), [&"near"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpatialHashGrid2d<T> {
+    cell_size: f32,
+    entries: Vec<(Rect, T)>,
+    cells: HashMap<IVec2, Vec<usize>>,
+    /// Values whose bounds cover too many cells to store in each of them.
+    oversized: Vec<usize>,
+}
+
+impl<T> SpatialHashGrid2d<T> {
+    /// The most cells a value is inserted into, see [`insert`](Self::insert).
+    const MAX_CELLS_PER_VALUE: i64 = 1024;
+
+    /// Creates an empty grid with square cells of the given side length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_size` is not positive and finite.
+    pub fn new(cell_size: f32) -> Self {
+        assert!(
+            cell_size > 0.0 && cell_size.is_finite(),
+            "cell size must be positive and finite"
+        );
+        Self {
+            cell_size,
+            entries: Vec::new(),
+            cells: HashMap::new(),
+            oversized: Vec::new(),
+        }
+    }
+
+    /// The side length of the grid's cells.
+    #[inline]
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// The number of values in the grid.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the grid contains no values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The cell containing `point`.
+    ///
+    /// Cells are half-open, so a point on the border between two cells belongs to the cell with
+    /// the larger coordinates, including for negative coordinates.
+    #[inline]
+    pub fn cell_at(&self, point: Vec2) -> IVec2 {
+        (point / self.cell_size).floor().as_ivec2()
+    }
+
+    /// The number of cells from `min` to `max`, inclusive.
+    #[inline]
+    fn cell_count(min: IVec2, max: IVec2) -> i64 {
+        let size = (max.as_i64vec2() - min.as_i64vec2() + 1).max(I64Vec2::ZERO);
+        size.x.saturating_mul(size.y)
+    }
+
+    /// Inserts a value with the given bounds into every cell the bounds overlap.
+    ///
+    /// Bounds covering more than 1024 cells, including infinite bounds, are not stored in cells.
+    /// Instead, every query checks them, which is faster than visiting that many cells as long
+    /// as there are few of them.
+    pub fn insert(&mut self, bounds: Rect, value: T) {
+        let index = self.entries.len();
+        let (min, max) = (self.cell_at(bounds.min), self.cell_at(bounds.max));
+        if Self::cell_count(min, max) > Self::MAX_CELLS_PER_VALUE {
+            self.oversized.push(index);
+        } else {
+            for y in min.y..=max.y {
+                for x in min.x..=max.x {
+                    self.cells.entry(IVec2::new(x, y)).or_default().push(index);
+                }
+            }
+        }
+        self.entries.push((bounds, value));
+    }
+
+    /// Removes all values.
+    ///
+    /// The buckets of the cells that had values are kept for reuse, while the buckets that
+    /// were already empty are dropped, so the grid doesn't grow as objects move around.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.oversized.clear();
+        self.cells.retain(|_, bucket| {
+            let in_use = !bucket.is_empty();
+            bucket.clear();
+            in_use
+        });
+    }
+
+    /// Returns the values whose bounds overlap `area`, including bounds that only touch it, in
+    /// insertion order.
+    pub fn query_rect(&self, area: Rect) -> impl Iterator<Item = &T> {
+        let (min, max) = (self.cell_at(area.min), self.cell_at(area.max));
+        let mut indices = self.oversized.clone();
+        if Self::cell_count(min, max) > self.cells.len() as i64 {
+            // Visiting the occupied cells is cheaper than every cell in a huge area.
+            for (cell, bucket) in &self.cells {
+                if cell.cmpge(min).all() && cell.cmple(max).all() {
+                    indices.extend_from_slice(bucket);
+                }
+            }
+        } else {
+            for y in min.y..=max.y {
+                for x in min.x..=max.x {
+                    if let Some(bucket) = self.cells.get(&IVec2::new(x, y)) {
+                        indices.extend_from_slice(bucket);
+                    }
+                }
+            }
+        }
+        // Values spanning several cells show up once per cell.
+        indices.sort_unstable();
+        indices.dedup();
+
+        indices.into_iter().filter_map(move |index| {
+            let (bounds, value) = &self.entries[index];
+            let overlaps = bounds.min.cmple(area.max).all() && area.min.cmple(bounds.max).all();
+            overlaps.then_some(value)
+        })
+    }
+
+    /// Returns the values whose bounds contain `point`, including their edges, in insertion order.
+    pub fn query_point(&self, point: Vec2) -> impl Iterator<Item = &T> {
+        // Indices within a single cell are unique and already in insertion order, and a value
+        // is either in cells or oversized.
+        let bucket = self.cells.get(&self.cell_at(point));
+        let mut indices: Vec<usize> = bucket.into_iter().flatten().copied().collect();
+        if !self.oversized.is_empty() {
+            indices.extend_from_slice(&self.oversized);
+            indices.sort_unstable();
+        }
+        indices
+            .into_iter()
+            .map(|index| &self.entries[index])
+            .filter(move |(bounds, _)| bounds.contains(point))
+            .map(|(_, value)| value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn deterministic_rand() -> StdRng {
+        StdRng::seed_from_u64(42)
+    }
+
+    fn random_rects(count: usize, max_size: f32) -> Vec<Rect> {
+        let mut rng = deterministic_rand();
+        (0..count)
+            .map(|_| {
+                let center = Vec2::new(rng.gen(), rng.gen()) * 200.0 - 100.0;
+                let size = Vec2::new(rng.gen(), rng.gen()) * max_size;
+                Rect::from_center_size(center, size)
+            })
+            .collect()
+    }
+
+    fn overlaps(a: Rect, b: Rect) -> bool {
+        a.min.cmple(b.max).all() && b.min.cmple(a.max).all()
+    }
+
+    #[test]
+    fn cell_at_negative_coordinates() {
+        let grid = SpatialHashGrid2d::<()>::new(2.0);
+        assert_eq!(grid.cell_at(Vec2::new(0.0, 1.9)), IVec2::new(0, 0));
+        assert_eq!(grid.cell_at(Vec2::new(2.0, 4.0)), IVec2::new(1, 2));
+        assert_eq!(grid.cell_at(Vec2::new(-0.1, -2.0)), IVec2::new(-1, -1));
+        assert_eq!(grid.cell_at(Vec2::new(-2.1, -4.5)), IVec2::new(-2, -3));
+    }
+
+    #[test]
+    fn query_rect_matches_brute_force() {
+        let rects = random_rects(500, 15.0);
+        let mut grid = SpatialHashGrid2d::new(8.0);
+        for (i, rect) in rects.iter().enumerate() {
+            grid.insert(*rect, i);
+        }
+        assert_eq!(grid.len(), rects.len());
+
+        for query in random_rects(100, 40.0) {
+            let expected: Vec<usize> = (0..rects.len())
+                .filter(|&i| overlaps(rects[i], query))
+                .collect();
+            let found: Vec<usize> = grid.query_rect(query).copied().collect();
+            assert_eq!(found, expected);
+        }
+    }
+
+    #[test]
+    fn query_point_matches_brute_force() {
+        let rects = random_rects(500, 15.0);
+        let mut grid = SpatialHashGrid2d::new(8.0);
+        for (i, rect) in rects.iter().enumerate() {
+            grid.insert(*rect, i);
+        }
+
+        let mut rng = deterministic_rand();
+        for _ in 0..200 {
+            let point = Vec2::new(rng.gen(), rng.gen()) * 200.0 - 100.0;
+            let expected: Vec<usize> = (0..rects.len())
+                .filter(|&i| rects[i].contains(point))
+                .collect();
+            let found: Vec<usize> = grid.query_point(point).copied().collect();
+            assert_eq!(found, expected);
+        }
+
+        // Edges and corners count, also on cell borders
+        let mut grid = SpatialHashGrid2d::new(1.0);
+        grid.insert(Rect::new(-1.0, -1.0, 0.0, 0.0), "a");
+        assert_eq!(grid.query_point(Vec2::ZERO).collect::<Vec<_>>(), [&"a"]);
+        assert_eq!(grid.query_point(Vec2::NEG_ONE).collect::<Vec<_>>(), [&"a"]);
+        assert_eq!(grid.query_point(Vec2::new(0.0, 0.1)).count(), 0);
+    }
+
+    #[test]
+    fn huge_bounds_and_queries() {
+        let mut rects = random_rects(100, 15.0);
+        rects.extend([
+            Rect::new(-1e30, -5.0, 1e30, 5.0),
+            Rect::new(-1e6, -1e6, 1e6, 1e6),
+            Rect {
+                min: Vec2::NEG_INFINITY,
+                max: Vec2::INFINITY,
+            },
+        ]);
+        rects.extend(random_rects(100, 15.0));
+        let mut grid = SpatialHashGrid2d::new(1.0);
+        for (i, rect) in rects.iter().enumerate() {
+            grid.insert(*rect, i);
+        }
+        // Huge bounds aren't stored in every cell they cover.
+        assert!(grid.cells.len() <= 200 * 16 * 16);
+
+        let queries = [
+            Rect::new(-1e30, -1e30, 1e30, 1e30),
+            Rect::new(0.0, -1e30, 1.0, 1e30),
+            Rect {
+                min: Vec2::NEG_INFINITY,
+                max: Vec2::INFINITY,
+            },
+            Rect::new(10.0, 10.0, 20.0, 20.0),
+        ];
+        for query in queries {
+            let expected: Vec<usize> = (0..rects.len())
+                .filter(|&i| overlaps(rects[i], query))
+                .collect();
+            let found: Vec<usize> = grid.query_rect(query).copied().collect();
+            assert_eq!(found, expected);
+        }
+
+        let point = rects[0].center();
+        let expected: Vec<usize> = (0..rects.len())
+            .filter(|&i| rects[i].contains(point))
+            .collect();
+        let found: Vec<usize> = grid.query_point(point).copied().collect();
+        assert_eq!(found, expected);
+        assert!(found.contains(&102));
+    }
+
+    #[test]
+    fn clear_drops_unused_cells() {
+        let mut grid = SpatialHashGrid2d::new(1.0);
+        for i in 0..100 {
+            // An object moving to a new cell every frame
+            grid.clear();
+            let position = Vec2::splat(i as f32 * 2.0 + 0.5);
+            grid.insert(Rect::from_center_size(position, Vec2::splat(0.5)), i);
+            assert_eq!(grid.query_point(position).collect::<Vec<_>>(), [&i]);
+        }
+        // Only the cells of the last two frames remain.
+        assert_eq!(grid.cells.len(), 2);
+    }
+
+    #[test]
+    fn clear_and_reuse() {
+        let mut grid = SpatialHashGrid2d::new(4.0);
+        let area = Rect::new(-10.0, -10.0, 10.0, 10.0);
+        grid.insert(area, 1);
+        grid.clear();
+        assert!(grid.is_empty());
+        assert_eq!(grid.query_rect(area).count(), 0);
+
+        grid.insert(Rect::new(1.0, 1.0, 2.0, 2.0), 2);
+        grid.insert(Rect::new(-3.0, 1.0, 2.0, 2.0), 3);
+        assert_eq!(grid.query_rect(area).collect::<Vec<_>>(), [&2, &3]);
+    }
+}
@@ -15,6 +15,7 @@ use bevy_derive::EnumVariantMeta;
 use bevy_ecs::system::{lifetimeless::SRes, SystemParamItem};
 use bevy_math::*;
 use bevy_reflect::{TypePath, TypeUuid};
+use bevy_transform::components::Transform;
 use bevy_utils::{tracing::error, Hashed};
 use std::{collections::BTreeMap, hash::Hash, iter::FusedIterator};
 use thiserror::Error;
@@ -423,20 +424,23 @@ impl Mesh {
         }
     }
 
-    /// Calculates the [`Mesh::ATTRIBUTE_NORMAL`] of a mesh.
+    /// Calculates the [`Mesh::ATTRIBUTE_NORMAL`] of a mesh, with each triangle's vertices facing
+    /// the same way as the triangle.
+    ///
+    /// If the mesh has [`Indices`], the vertices are duplicated first with
+    /// [`Mesh::duplicate_vertices`] so that no vertices are shared between triangles.
     ///
     /// # Panics
-    /// Panics if [`Indices`] are set or [`Mesh::ATTRIBUTE_POSITION`] is not of type `float3` or
+    /// Panics if [`Mesh::ATTRIBUTE_POSITION`] is not of type `float3` or
     /// if the mesh has any other topology than [`PrimitiveTopology::TriangleList`].
-    /// Consider calling [`Mesh::duplicate_vertices`] or export your mesh with normal attributes.
     pub fn compute_flat_normals(&mut self) {
-        assert!(self.indices().is_none(), "`compute_flat_normals` can't work on indexed geometry. Consider calling `Mesh::duplicate_vertices`.");
-
         assert!(
             matches!(self.primitive_topology, PrimitiveTopology::TriangleList),
             "`compute_flat_normals` can only work on `TriangleList`s"
         );
 
+        self.duplicate_vertices();
+
         let positions = self
             .attribute(Mesh::ATTRIBUTE_POSITION)
             .unwrap()
@@ -452,6 +456,79 @@ impl Mesh {
         self.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
     }
 
+    /// Calculates the [`Mesh::ATTRIBUTE_NORMAL`] of a mesh, with each vertex facing the average
+    /// direction of the triangles sharing it, weighted by their area.
+    ///
+    /// Only vertices shared through the [`Indices`] are smoothed, so a mesh without indices gets
+    /// the same normals as with [`Mesh::compute_flat_normals`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and leaves the mesh unchanged if its topology is not
+    /// [`PrimitiveTopology::TriangleList`].
+    ///
+    /// # Panics
+    /// Panics if [`Mesh::ATTRIBUTE_POSITION`] is not of type `float3`.
+    pub fn compute_smooth_normals(&mut self) -> Result<(), MeshSmoothNormalsError> {
+        if self.primitive_topology != PrimitiveTopology::TriangleList {
+            return Err(MeshSmoothNormalsError::UnsupportedTopology(
+                self.primitive_topology,
+            ));
+        }
+
+        let positions = self
+            .attribute(Mesh::ATTRIBUTE_POSITION)
+            .unwrap()
+            .as_float3()
+            .expect("`Mesh::ATTRIBUTE_POSITION` vertex attributes should be of type `float3`");
+
+        let mut normals = vec![Vec3::ZERO; positions.len()];
+        let mut add_triangle = |[a, b, c]: [usize; 3]| {
+            let [pa, pb, pc] = [a, b, c].map(|i| Vec3::from(positions[i]));
+            // The length of the cross product is twice the area of the triangle.
+            let normal = (pb - pa).cross(pc - pa);
+            for i in [a, b, c] {
+                normals[i] += normal;
+            }
+        };
+        match &self.indices {
+            Some(indices) => {
+                let indices: Vec<usize> = indices.iter().collect();
+                for triangle in indices.chunks_exact(3) {
+                    add_triangle([triangle[0], triangle[1], triangle[2]]);
+                }
+            }
+            None => {
+                for i in (0..positions.len() / 3).map(|triangle| triangle * 3) {
+                    add_triangle([i, i + 1, i + 2]);
+                }
+            }
+        }
+
+        let normals: Vec<[f32; 3]> = normals
+            .into_iter()
+            .map(|normal| normal.normalize_or_zero().to_array())
+            .collect();
+        self.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        Ok(())
+    }
+
+    /// Consumes the mesh and returns a mesh with flat normals.
+    ///
+    /// See [`Mesh::compute_flat_normals`].
+    pub fn with_computed_flat_normals(mut self) -> Self {
+        self.compute_flat_normals();
+        self
+    }
+
+    /// Consumes the mesh and returns a mesh with smooth normals.
+    ///
+    /// See [`Mesh::compute_smooth_normals`].
+    pub fn with_computed_smooth_normals(mut self) -> Result<Self, MeshSmoothNormalsError> {
+        self.compute_smooth_normals()?;
+        Ok(self)
+    }
+
     /// Generate tangents for the mesh using the `mikktspace` algorithm.
     ///
     /// Sets the [`Mesh::ATTRIBUTE_TANGENT`] attribute if successful.
@@ -462,6 +539,295 @@ impl Mesh {
         Ok(())
     }
 
+    /// Consumes the mesh and returns a mesh with the given transform applied to its vertices.
+    ///
+    /// See [`Mesh::transform_by`].
+    pub fn transformed_by(mut self, transform: Transform) -> Self {
+        self.transform_by(transform);
+        self
+    }
+
+    /// Applies the given transform to the vertices of the mesh: the scale first, then the
+    /// rotation and then the translation.
+    ///
+    /// This bakes a transform into the mesh, for example to combine static geometry into a
+    /// single mesh. The positions, normals and tangents are transformed if they are present and
+    /// use the standard formats.
+    pub fn transform_by(&mut self, transform: Transform) {
+        self.scale_by(transform.scale);
+        self.rotate_by(transform.rotation);
+        self.translate_by(transform.translation);
+    }
+
+    /// Consumes the mesh and returns a mesh with its vertices translated by the given offset.
+    ///
+    /// See [`Mesh::translate_by`].
+    pub fn translated_by(mut self, translation: Vec3) -> Self {
+        self.translate_by(translation);
+        self
+    }
+
+    /// Translates the [`Mesh::ATTRIBUTE_POSITION`] of the vertices by the given offset.
+    pub fn translate_by(&mut self, translation: Vec3) {
+        if let Some(VertexAttributeValues::Float32x3(positions)) =
+            self.attribute_mut(Mesh::ATTRIBUTE_POSITION)
+        {
+            for position in positions {
+                *position = (Vec3::from(*position) + translation).to_array();
+            }
+        }
+    }
+
+    /// Consumes the mesh and returns a mesh with its vertices rotated by the given rotation.
+    ///
+    /// See [`Mesh::rotate_by`].
+    pub fn rotated_by(mut self, rotation: Quat) -> Self {
+        self.rotate_by(rotation);
+        self
+    }
+
+    /// Rotates the positions, normals and tangents of the vertices around the origin.
+    pub fn rotate_by(&mut self, rotation: Quat) {
+        if let Some(VertexAttributeValues::Float32x3(positions)) =
+            self.attribute_mut(Mesh::ATTRIBUTE_POSITION)
+        {
+            for position in positions {
+                *position = (rotation * Vec3::from(*position)).to_array();
+            }
+        }
+        if let Some(VertexAttributeValues::Float32x3(normals)) =
+            self.attribute_mut(Mesh::ATTRIBUTE_NORMAL)
+        {
+            for normal in normals {
+                *normal = (rotation * Vec3::from(*normal))
+                    .normalize_or_zero()
+                    .to_array();
+            }
+        }
+        if let Some(VertexAttributeValues::Float32x4(tangents)) =
+            self.attribute_mut(Mesh::ATTRIBUTE_TANGENT)
+        {
+            for tangent in tangents {
+                let rotated = rotation * Vec3::from_slice(tangent);
+                *tangent = rotated.normalize_or_zero().extend(tangent[3]).to_array();
+            }
+        }
+    }
+
+    /// Consumes the mesh and returns a mesh with its vertices scaled by the given factors.
+    ///
+    /// See [`Mesh::scale_by`].
+    pub fn scaled_by(mut self, scale: Vec3) -> Self {
+        self.scale_by(scale);
+        self
+    }
+
+    /// Scales the positions, normals and tangents of the vertices relative to the origin.
+    ///
+    /// Normals stay perpendicular to the surface under a non-uniform scale. If the scale mirrors
+    /// the mesh, which is when an odd number of its factors are negative, the winding of the
+    /// triangles of a [`PrimitiveTopology::TriangleList`] is flipped so that the front faces still
+    /// face outwards. Other topologies keep their winding.
+    pub fn scale_by(&mut self, scale: Vec3) {
+        if let Some(VertexAttributeValues::Float32x3(positions)) =
+            self.attribute_mut(Mesh::ATTRIBUTE_POSITION)
+        {
+            for position in positions {
+                *position = (Vec3::from(*position) * scale).to_array();
+            }
+        }
+
+        // Normals are transformed by the inverse transpose of the scale. Multiplying by the
+        // cofactors instead of dividing by the scale also works when a factor is zero.
+        let determinant = scale.x * scale.y * scale.z;
+        let mirrored = determinant < 0.0;
+        if let Some(VertexAttributeValues::Float32x3(normals)) =
+            self.attribute_mut(Mesh::ATTRIBUTE_NORMAL)
+        {
+            let cofactors = scale.yzx() * scale.zxy() * determinant.signum();
+            for normal in normals {
+                *normal = (Vec3::from(*normal) * cofactors)
+                    .normalize_or_zero()
+                    .to_array();
+            }
+        }
+
+        if let Some(VertexAttributeValues::Float32x4(tangents)) =
+            self.attribute_mut(Mesh::ATTRIBUTE_TANGENT)
+        {
+            for tangent in tangents {
+                let scaled = Vec3::from_slice(tangent) * scale;
+                let handedness = if mirrored { -tangent[3] } else { tangent[3] };
+                *tangent = scaled.normalize_or_zero().extend(handedness).to_array();
+            }
+        }
+
+        if mirrored && self.primitive_topology == PrimitiveTopology::TriangleList {
+            self.flip_triangle_winding();
+        }
+    }
+
+    /// Appends the vertices and indices of `other` to this mesh.
+    ///
+    /// This combines meshes that are rendered with the same material into a single mesh, which
+    /// needs fewer draw calls. Bake the transforms into the meshes with
+    /// [`Mesh::transform_by`] first.
+    ///
+    /// The indices of `other` are offset to point to its vertices in the merged mesh. If only one
+    /// of the meshes has indices, the other mesh gets indices for its vertices in order.
+    /// [`Indices::U16`] are converted to [`Indices::U32`] if the merged mesh has too many vertices
+    /// for them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and leaves this mesh unchanged if the meshes have different topologies,
+    /// different sets of vertex attributes, attributes with different formats, or morph targets.
+    ///
+    /// Meshes with [`PrimitiveTopology::TriangleStrip`] or [`PrimitiveTopology::LineStrip`]
+    /// topology can't be merged either, since joining the strips would connect the last vertex of
+    /// this mesh to the first vertex of `other`.
+    pub fn merge(&mut self, other: &Mesh) -> Result<(), MeshMergeError> {
+        if self.primitive_topology != other.primitive_topology {
+            return Err(MeshMergeError::IncompatibleTopology {
+                mesh: self.primitive_topology,
+                other: other.primitive_topology,
+            });
+        }
+        if matches!(
+            self.primitive_topology,
+            PrimitiveTopology::TriangleStrip | PrimitiveTopology::LineStrip
+        ) {
+            return Err(MeshMergeError::UnsupportedTopology(self.primitive_topology));
+        }
+        if self.morph_targets.is_some() || other.morph_targets.is_some() {
+            return Err(MeshMergeError::MorphTargets);
+        }
+        if !self.attributes.keys().eq(other.attributes.keys()) {
+            return Err(MeshMergeError::IncompatibleAttributes);
+        }
+        for (data, other_data) in self.attributes.values().zip(other.attributes.values()) {
+            if VertexFormat::from(&data.values) != VertexFormat::from(&other_data.values) {
+                return Err(MeshMergeError::IncompatibleFormats(data.attribute.name));
+            }
+        }
+
+        let vertex_count = self.count_vertices();
+        let other_vertex_count = other.count_vertices();
+        let indices = match (self.indices.take(), &other.indices) {
+            (None, None) => None,
+            (indices, other_indices) => {
+                let indices = indices.unwrap_or_else(|| sequential_indices(vertex_count));
+                let other_indices = other_indices
+                    .clone()
+                    .unwrap_or_else(|| sequential_indices(other_vertex_count));
+                let total_vertices = vertex_count + other_vertex_count;
+                Some(match (indices, other_indices) {
+                    (Indices::U16(mut indices), Indices::U16(other_indices))
+                        if total_vertices <= u16::MAX as usize + 1 =>
+                    {
+                        let offset = vertex_count as u16;
+                        indices.extend(other_indices.iter().map(|i| i + offset));
+                        Indices::U16(indices)
+                    }
+                    (indices, other_indices) => {
+                        let offset = vertex_count as u32;
+                        let mut indices: Vec<u32> = indices.iter().map(|i| i as u32).collect();
+                        indices.extend(other_indices.iter().map(|i| i as u32 + offset));
+                        Indices::U32(indices)
+                    }
+                })
+            }
+        };
+        self.indices = indices;
+
+        for (data, other_data) in self.attributes.values_mut().zip(other.attributes.values()) {
+            data.values.extend_from(&other_data.values);
+        }
+        Ok(())
+    }
+
+    /// Merges all of the meshes into one mesh, as if calling [`Mesh::merge`] for each of them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the meshes can't be merged, or if there are no meshes.
+    pub fn from_iter_merged<'a>(
+        meshes: impl IntoIterator<Item = &'a Mesh>,
+    ) -> Result<Mesh, MeshMergeError> {
+        let mut meshes = meshes.into_iter();
+        let mut merged = meshes.next().ok_or(MeshMergeError::NoMeshes)?.clone();
+        for mesh in meshes {
+            merged.merge(mesh)?;
+        }
+        Ok(merged)
+    }
+
+    /// Turns the mesh inside out, by reversing the winding of each triangle, negating the
+    /// [`Mesh::ATTRIBUTE_NORMAL`] and flipping the handedness stored in the `w` component of the
+    /// [`Mesh::ATTRIBUTE_TANGENT`].
+    ///
+    /// This makes the inside of a closed mesh visible instead of the outside, for example for
+    /// skyboxes or rooms. Textures are mirrored when seen from the inside. To avoid this, build a
+    /// [`shape::Box`](crate::mesh::shape::Box) or [`shape::UVSphere`](crate::mesh::shape::UVSphere)
+    /// with its `inside_out` option, which also mirrors the UVs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and leaves the mesh unchanged if its topology is not
+    /// [`PrimitiveTopology::TriangleList`].
+    pub fn invert_winding(&mut self) -> Result<(), MeshWindingInversionError> {
+        if self.primitive_topology != PrimitiveTopology::TriangleList {
+            return Err(MeshWindingInversionError::UnsupportedTopology(
+                self.primitive_topology,
+            ));
+        }
+
+        self.flip_triangle_winding();
+        if let Some(VertexAttributeValues::Float32x3(normals)) =
+            self.attribute_mut(Mesh::ATTRIBUTE_NORMAL)
+        {
+            for normal in normals {
+                *normal = (-Vec3::from(*normal)).to_array();
+            }
+        }
+        if let Some(VertexAttributeValues::Float32x4(tangents)) =
+            self.attribute_mut(Mesh::ATTRIBUTE_TANGENT)
+        {
+            for tangent in tangents {
+                tangent[3] = -tangent[3];
+            }
+        }
+        Ok(())
+    }
+
+    /// Consumes the mesh and returns it turned inside out.
+    ///
+    /// See [`Mesh::invert_winding`].
+    pub fn with_inverted_winding(mut self) -> Result<Self, MeshWindingInversionError> {
+        self.invert_winding()?;
+        Ok(self)
+    }
+
+    /// Swaps the last two vertices of each triangle of a [`PrimitiveTopology::TriangleList`].
+    fn flip_triangle_winding(&mut self) {
+        match &mut self.indices {
+            Some(Indices::U16(indices)) => {
+                indices.chunks_exact_mut(3).for_each(|t| t.swap(1, 2));
+            }
+            Some(Indices::U32(indices)) => {
+                indices.chunks_exact_mut(3).for_each(|t| t.swap(1, 2));
+            }
+            None => {
+                // Reorder the vertices themselves.
+                let order = (0..self.count_vertices() as u32 / 3)
+                    .flat_map(|t| [3 * t, 3 * t + 2, 3 * t + 1])
+                    .collect();
+                self.indices = Some(Indices::U32(order));
+                self.duplicate_vertices();
+            }
+        }
+    }
+
     /// Compute the Axis-Aligned Bounding Box of the mesh vertices in model space
     pub fn compute_aabb(&self) -> Option<Aabb> {
         let Some(VertexAttributeValues::Float32x3(values)) =
@@ -748,6 +1114,46 @@ impl VertexAttributeValues {
         self.len() == 0
     }
 
+    /// Appends the values of `other`, which must have the same format.
+    fn extend_from(&mut self, other: &VertexAttributeValues) {
+        use VertexAttributeValues::*;
+        match (self, other) {
+            (Float32(values), Float32(other)) => values.extend_from_slice(other),
+            (Sint32(values), Sint32(other)) => values.extend_from_slice(other),
+            (Uint32(values), Uint32(other)) => values.extend_from_slice(other),
+            (Float32x2(values), Float32x2(other)) => values.extend_from_slice(other),
+            (Sint32x2(values), Sint32x2(other)) => values.extend_from_slice(other),
+            (Uint32x2(values), Uint32x2(other)) => values.extend_from_slice(other),
+            (Float32x3(values), Float32x3(other)) => values.extend_from_slice(other),
+            (Sint32x3(values), Sint32x3(other)) => values.extend_from_slice(other),
+            (Uint32x3(values), Uint32x3(other)) => values.extend_from_slice(other),
+            (Float32x4(values), Float32x4(other)) => values.extend_from_slice(other),
+            (Sint32x4(values), Sint32x4(other)) => values.extend_from_slice(other),
+            (Uint32x4(values), Uint32x4(other)) => values.extend_from_slice(other),
+            (Sint16x2(values), Sint16x2(other)) => values.extend_from_slice(other),
+            (Snorm16x2(values), Snorm16x2(other)) => values.extend_from_slice(other),
+            (Uint16x2(values), Uint16x2(other)) => values.extend_from_slice(other),
+            (Unorm16x2(values), Unorm16x2(other)) => values.extend_from_slice(other),
+            (Sint16x4(values), Sint16x4(other)) => values.extend_from_slice(other),
+            (Snorm16x4(values), Snorm16x4(other)) => values.extend_from_slice(other),
+            (Uint16x4(values), Uint16x4(other)) => values.extend_from_slice(other),
+            (Unorm16x4(values), Unorm16x4(other)) => values.extend_from_slice(other),
+            (Sint8x2(values), Sint8x2(other)) => values.extend_from_slice(other),
+            (Snorm8x2(values), Snorm8x2(other)) => values.extend_from_slice(other),
+            (Uint8x2(values), Uint8x2(other)) => values.extend_from_slice(other),
+            (Unorm8x2(values), Unorm8x2(other)) => values.extend_from_slice(other),
+            (Sint8x4(values), Sint8x4(other)) => values.extend_from_slice(other),
+            (Snorm8x4(values), Snorm8x4(other)) => values.extend_from_slice(other),
+            (Uint8x4(values), Uint8x4(other)) => values.extend_from_slice(other),
+            (Unorm8x4(values), Unorm8x4(other)) => values.extend_from_slice(other),
+            (values, other) => panic!(
+                "cannot extend {:?} values with {:?} values",
+                VertexFormat::from(&*values),
+                VertexFormat::from(other)
+            ),
+        }
+    }
+
     /// Returns the values as float triples if possible.
     pub fn as_float3(&self) -> Option<&[[f32; 3]]> {
         match self {
@@ -1021,6 +1427,45 @@ impl bevy_mikktspace::Geometry for MikktspaceGeometryHelper<'_> {
     }
 }
 
+/// Returns indices for the vertices of a mesh in order.
+fn sequential_indices(vertex_count: usize) -> Indices {
+    Indices::U32((0..vertex_count as u32).collect())
+}
+
+/// An error when merging meshes with [`Mesh::merge`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum MeshMergeError {
+    #[error("cannot merge a mesh with {other:?} topology into a mesh with {mesh:?} topology")]
+    IncompatibleTopology {
+        mesh: PrimitiveTopology,
+        other: PrimitiveTopology,
+    },
+    #[error("cannot merge meshes with {0:?} topology")]
+    UnsupportedTopology(PrimitiveTopology),
+    #[error("the meshes have different vertex attributes")]
+    IncompatibleAttributes,
+    #[error("the '{0}' vertex attribute has different formats in the meshes")]
+    IncompatibleFormats(&'static str),
+    #[error("meshes with morph targets cannot be merged")]
+    MorphTargets,
+    #[error("there are no meshes to merge")]
+    NoMeshes,
+}
+
+/// An error when inverting the winding of a mesh with [`Mesh::invert_winding`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum MeshWindingInversionError {
+    #[error("cannot invert the winding of a mesh with {0:?} topology")]
+    UnsupportedTopology(PrimitiveTopology),
+}
+
+/// An error when computing smooth normals with [`Mesh::compute_smooth_normals`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum MeshSmoothNormalsError {
+    #[error("cannot compute smooth normals for a mesh with {0:?} topology")]
+    UnsupportedTopology(PrimitiveTopology),
+}
+
 #[derive(thiserror::Error, Debug)]
 /// Failed to generate tangents for the mesh.
 pub enum GenerateTangentsError {
@@ -1103,8 +1548,38 @@ fn generate_tangents_for_mesh(mesh: &Mesh) -> Result<Vec<[f32; 4]>, GenerateTang
 
 #[cfg(test)]
 mod tests {
-    use super::Mesh;
-    use wgpu::PrimitiveTopology;
+    use super::{
+        Mesh, MeshMergeError, MeshSmoothNormalsError, MeshVertexAttribute,
+        MeshWindingInversionError,
+    };
+    use crate::mesh::{shape, Indices, VertexAttributeValues};
+    use bevy_math::{Quat, Vec3, Vec4};
+    use bevy_transform::components::Transform;
+    use wgpu::{PrimitiveTopology, VertexFormat};
+
+    /// Checks that the normals are unit length and that every triangle faces the same way as the
+    /// normals of its vertices.
+    fn check_normals(mesh: &Mesh) {
+        let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION).unwrap();
+        let positions = positions.as_float3().unwrap();
+        let normals = mesh.attribute(Mesh::ATTRIBUTE_NORMAL).unwrap();
+        let normals = normals.as_float3().unwrap();
+        for normal in normals {
+            assert!((Vec3::from(*normal).length() - 1.0).abs() < 1e-5);
+        }
+
+        let indices: Vec<usize> = match mesh.indices() {
+            Some(indices) => indices.iter().collect(),
+            None => (0..positions.len()).collect(),
+        };
+        for triangle in indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| Vec3::from(positions[triangle[i]]));
+            let face_normal = (b - a).cross(c - a).normalize();
+            for &i in triangle {
+                assert!(face_normal.dot(Vec3::from(normals[i])) > 0.0);
+            }
+        }
+    }
 
     #[test]
     #[should_panic]
@@ -1112,4 +1587,350 @@ mod tests {
         let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
         mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, vec![[0.0, 0.0, 0.0]]);
     }
+
+    #[test]
+    fn transform_mesh() {
+        let mesh = Mesh::from(shape::Cube::new(2.0)).translated_by(Vec3::Y);
+        let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION).unwrap();
+        let positions = positions.as_float3().unwrap();
+        assert_eq!(positions[0], [-1.0, 0.0, 1.0]);
+
+        let transform = Transform::from_xyz(1.0, 2.0, 3.0)
+            .with_rotation(Quat::from_rotation_y(0.5))
+            .with_scale(Vec3::new(3.0, 0.5, 2.0));
+        let transformed = mesh.clone().transformed_by(transform);
+        let positions = transformed.attribute(Mesh::ATTRIBUTE_POSITION).unwrap();
+        let positions = positions.as_float3().unwrap();
+        let expected = transform.transform_point(Vec3::new(-1.0, 0.0, 1.0));
+        assert!(Vec3::from(positions[0]).abs_diff_eq(expected, 1e-6));
+        check_normals(&transformed);
+    }
+
+    #[test]
+    fn scale_normals() {
+        let sphere = Mesh::from(shape::UVSphere {
+            radius: 1.0,
+            sectors: 12,
+            stacks: 6,
+            ..Default::default()
+        });
+        let scale = Vec3::new(4.0, 0.5, 1.0);
+        let ellipsoid = sphere.scaled_by(scale);
+        check_normals(&ellipsoid);
+
+        // The normals of an ellipsoid are the gradient of `(x / a)^2 + (y / b)^2 + (z / c)^2`.
+        let positions = ellipsoid.attribute(Mesh::ATTRIBUTE_POSITION).unwrap();
+        let normals = ellipsoid.attribute(Mesh::ATTRIBUTE_NORMAL).unwrap();
+        for (position, normal) in positions
+            .as_float3()
+            .unwrap()
+            .iter()
+            .zip(normals.as_float3().unwrap())
+        {
+            let gradient = (Vec3::from(*position) / (scale * scale)).normalize();
+            assert!(gradient.abs_diff_eq(Vec3::from(*normal), 1e-5));
+        }
+    }
+
+    #[test]
+    fn mirroring_flips_winding() {
+        let mut mesh = Mesh::from(shape::Box::new(1.0, 2.0, 3.0));
+        mesh.insert_attribute(Mesh::ATTRIBUTE_TANGENT, vec![[1.0, 0.0, 0.0, 1.0]; 24]);
+        let Some(Indices::U32(indices)) = mesh.indices() else {
+            panic!("missing indices");
+        };
+        assert_eq!(indices[..3], [0, 1, 2]);
+
+        let mesh = mesh.scaled_by(Vec3::new(-1.0, 1.0, 1.0));
+        let Some(Indices::U32(indices)) = mesh.indices() else {
+            panic!("missing indices");
+        };
+        assert_eq!(indices[..3], [0, 2, 1]);
+        check_normals(&mesh);
+        let Some(VertexAttributeValues::Float32x4(tangents)) =
+            mesh.attribute(Mesh::ATTRIBUTE_TANGENT)
+        else {
+            panic!("unexpected tangent format");
+        };
+        assert_eq!(tangents[0], [-1.0, 0.0, 0.0, -1.0]);
+
+        // Without indices, the vertices are reordered.
+        let mut mesh = Mesh::from(shape::Box::new(1.0, 2.0, 3.0));
+        mesh.duplicate_vertices();
+        let mesh = mesh.scaled_by(Vec3::new(0.5, -2.0, 1.5));
+        assert!(mesh.indices().is_none());
+        check_normals(&mesh);
+    }
+
+    #[test]
+    fn merge_meshes() {
+        let mut mesh = Mesh::from(shape::Cube::new(1.0));
+        let other = Mesh::from(shape::Box::new(1.0, 2.0, 3.0)).translated_by(Vec3::X * 4.0);
+        mesh.merge(&other).unwrap();
+        assert_eq!(mesh.count_vertices(), 48);
+        let Some(Indices::U32(indices)) = mesh.indices() else {
+            panic!("missing indices");
+        };
+        assert_eq!(indices.len(), 72);
+        let Some(Indices::U32(other_indices)) = other.indices() else {
+            panic!("missing indices");
+        };
+        assert!(indices[36..]
+            .iter()
+            .copied()
+            .eq(other_indices.iter().map(|i| i + 24)));
+        // The merged indices point to the same positions as before.
+        let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION).unwrap();
+        let other_positions = other.attribute(Mesh::ATTRIBUTE_POSITION).unwrap();
+        for (i, other_i) in indices[36..].iter().zip(other_indices) {
+            assert_eq!(
+                positions.as_float3().unwrap()[*i as usize],
+                other_positions.as_float3().unwrap()[*other_i as usize]
+            );
+        }
+
+        let boxes = [1.0, 2.0, 3.0].map(|size| Mesh::from(shape::Cube::new(size)));
+        let merged = Mesh::from_iter_merged(&boxes).unwrap();
+        assert_eq!(merged.count_vertices(), 72);
+        assert_eq!(merged.indices().unwrap().len(), 108);
+        assert_eq!(
+            Mesh::from_iter_merged([]).unwrap_err(),
+            MeshMergeError::NoMeshes
+        );
+    }
+
+    #[test]
+    fn merge_u16_indices() {
+        let points = |count: usize| {
+            let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+            mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vec![[0.0; 3]; count]);
+            mesh.set_indices(Some(Indices::U16(vec![0, 1, count as u16 - 1])));
+            mesh
+        };
+
+        // The indices fit in `u16`, up to index 65535.
+        let mut mesh = points(30_000);
+        mesh.merge(&points(35_536)).unwrap();
+        let Some(Indices::U16(indices)) = mesh.indices() else {
+            panic!("expected u16 indices");
+        };
+        assert_eq!(indices, &[0, 1, 29_999, 30_000, 30_001, 65_535]);
+
+        mesh.merge(&points(10)).unwrap();
+        let Some(Indices::U32(indices)) = mesh.indices() else {
+            panic!("expected u32 indices");
+        };
+        assert_eq!(indices[6..], [65_536, 65_537, 65_545]);
+
+        // Meshes without indices get sequential indices.
+        let mut no_indices = Mesh::new(PrimitiveTopology::TriangleList);
+        no_indices.insert_attribute(Mesh::ATTRIBUTE_POSITION, vec![[0.0; 3]; 3]);
+        let mut mesh = points(3);
+        mesh.merge(&no_indices).unwrap();
+        assert_eq!(
+            mesh.indices().unwrap().iter().collect::<Vec<_>>(),
+            [0, 1, 2, 3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn merge_errors() {
+        let mut mesh = Mesh::from(shape::Cube::new(1.0));
+        let unchanged = mesh.clone();
+
+        let lines = Mesh::new(PrimitiveTopology::LineList);
+        assert_eq!(
+            mesh.merge(&lines).unwrap_err(),
+            MeshMergeError::IncompatibleTopology {
+                mesh: PrimitiveTopology::TriangleList,
+                other: PrimitiveTopology::LineList,
+            }
+        );
+
+        let strip = Mesh::from(shape::Polyline::new([Vec3::ZERO, Vec3::X]));
+        assert_eq!(
+            strip.clone().merge(&strip).unwrap_err(),
+            MeshMergeError::UnsupportedTopology(PrimitiveTopology::LineStrip)
+        );
+        let ribbon = Mesh::from(shape::Polyline::new([Vec3::ZERO, Vec3::X]).ribbon(1.0, Vec3::Y));
+        assert_eq!(
+            ribbon.clone().merge(&ribbon).unwrap_err(),
+            MeshMergeError::UnsupportedTopology(PrimitiveTopology::TriangleStrip)
+        );
+
+        let mut without_uvs = Mesh::from(shape::Cube::new(1.0));
+        without_uvs.remove_attribute(Mesh::ATTRIBUTE_UV_0);
+        assert_eq!(
+            mesh.merge(&without_uvs).unwrap_err(),
+            MeshMergeError::IncompatibleAttributes
+        );
+
+        const CUSTOM: MeshVertexAttribute =
+            MeshVertexAttribute::new("Custom", 100, VertexFormat::Float32);
+        const CUSTOM_UINT: MeshVertexAttribute =
+            MeshVertexAttribute::new("CustomUint", 100, VertexFormat::Uint32);
+        let mut mesh = mesh.clone();
+        mesh.insert_attribute(CUSTOM, vec![0.0; 24]);
+        let mut other = unchanged.clone();
+        other.insert_attribute(CUSTOM_UINT, vec![0_u32; 24]);
+        assert_eq!(
+            mesh.merge(&other).unwrap_err(),
+            MeshMergeError::IncompatibleFormats("Custom")
+        );
+        assert_eq!(mesh.count_vertices(), unchanged.count_vertices());
+        assert_eq!(mesh.indices().unwrap().len(), 36);
+    }
+
+    #[test]
+    fn flat_normals_of_indexed_mesh() {
+        let mut mesh = Mesh::from(shape::Box::new(1.0, 2.0, 3.0));
+        mesh.remove_attribute(Mesh::ATTRIBUTE_NORMAL);
+        let mesh = mesh.with_computed_flat_normals();
+        assert!(mesh.indices().is_none());
+        assert_eq!(mesh.count_vertices(), 36);
+        check_normals(&mesh);
+    }
+
+    #[test]
+    fn smooth_normals() {
+        // A subdivided plane
+        let mut plane = Mesh::from(shape::Plane::from_size(2.0).subdivisions(3));
+        plane.compute_smooth_normals().unwrap();
+        let normals = plane.attribute(Mesh::ATTRIBUTE_NORMAL).unwrap();
+        for normal in normals.as_float3().unwrap() {
+            assert_eq!(*normal, [0.0, 1.0, 0.0]);
+        }
+
+        // A cube sharing its corners, with a vertex in the middle of each face
+        let corners = (0..8).map(|i| [1, 2, 4].map(|bit| if i & bit == 0 { -1.0 } else { 1.0 }));
+        let centers = [
+            Vec3::X,
+            Vec3::NEG_X,
+            Vec3::Y,
+            Vec3::NEG_Y,
+            Vec3::Z,
+            Vec3::NEG_Z,
+        ];
+        let positions: Vec<[f32; 3]> = corners
+            .chain(centers.iter().map(|c| c.to_array()))
+            .collect();
+        let mut indices = Vec::new();
+        for (center_index, center) in centers.iter().enumerate() {
+            // The corners of the face, counterclockwise when looking at the face.
+            let u = Vec3::new(center.y, center.z, center.x);
+            let v = center.cross(u);
+            let face: Vec<u32> = [u + v, -u + v, -u - v, u - v]
+                .iter()
+                .map(|corner| {
+                    let position = (*center + *corner).to_array();
+                    positions.iter().position(|p| *p == position).unwrap() as u32
+                })
+                .collect();
+            for i in 0..4 {
+                indices.extend([8 + center_index as u32, face[i], face[(i + 1) % 4]]);
+            }
+        }
+        let mut cube = Mesh::new(PrimitiveTopology::TriangleList);
+        cube.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions.clone());
+        cube.set_indices(Some(Indices::U32(indices)));
+        let cube = cube.with_computed_smooth_normals().unwrap();
+        check_normals(&cube);
+
+        let normals = cube.attribute(Mesh::ATTRIBUTE_NORMAL).unwrap();
+        for (position, normal) in positions.iter().zip(normals.as_float3().unwrap()) {
+            let expected = Vec3::from(*position).normalize();
+            assert!(Vec3::from(*normal).abs_diff_eq(expected, 1e-6));
+        }
+
+        // Without indices, there are no shared vertices to smooth.
+        let mut mesh = Mesh::try_from(shape::Icosphere {
+            radius: 1.0,
+            subdivisions: 2,
+        })
+        .unwrap();
+        mesh.duplicate_vertices();
+        let flat = mesh.clone().with_computed_flat_normals();
+        let smooth = mesh.with_computed_smooth_normals().unwrap();
+        let (flat, smooth) = (
+            flat.attribute(Mesh::ATTRIBUTE_NORMAL)
+                .unwrap()
+                .as_float3()
+                .unwrap(),
+            smooth
+                .attribute(Mesh::ATTRIBUTE_NORMAL)
+                .unwrap()
+                .as_float3()
+                .unwrap(),
+        );
+        for (flat, smooth) in flat.iter().zip(smooth) {
+            assert!(Vec3::from(*flat).abs_diff_eq(Vec3::from(*smooth), 1e-6));
+        }
+
+        // Other topologies are rejected and left untouched.
+        for topology in [
+            PrimitiveTopology::LineList,
+            PrimitiveTopology::TriangleStrip,
+        ] {
+            let mut mesh = Mesh::new(topology);
+            mesh.insert_attribute(
+                Mesh::ATTRIBUTE_POSITION,
+                vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+            );
+            assert_eq!(
+                mesh.compute_smooth_normals(),
+                Err(MeshSmoothNormalsError::UnsupportedTopology(topology))
+            );
+            assert!(mesh.attribute(Mesh::ATTRIBUTE_NORMAL).is_none());
+            assert_eq!(
+                mesh.with_computed_smooth_normals().unwrap_err(),
+                MeshSmoothNormalsError::UnsupportedTopology(topology)
+            );
+        }
+    }
+
+    #[test]
+    fn invert_winding() {
+        let cube = Mesh::from(shape::Cube::new(1.0))
+            .with_inverted_winding()
+            .unwrap();
+        assert_eq!(cube.count_vertices(), 24);
+        assert_eq!(cube.indices().unwrap().len(), 36);
+        // The faces agree with the normals, which point to the center.
+        check_normals(&cube);
+        let positions = cube.attribute(Mesh::ATTRIBUTE_POSITION).unwrap();
+        let normals = cube.attribute(Mesh::ATTRIBUTE_NORMAL).unwrap();
+        for (position, normal) in positions
+            .as_float3()
+            .unwrap()
+            .iter()
+            .zip(normals.as_float3().unwrap())
+        {
+            assert!(Vec3::from(*normal).dot(Vec3::from(*position)) < 0.0);
+        }
+
+        // The tangents match the ones generated for the inverted mesh.
+        let mut mesh = Mesh::from(shape::Box::new(1.0, 2.0, 3.0));
+        mesh.generate_tangents().unwrap();
+        let mut inverted = mesh.with_inverted_winding().unwrap();
+        let tangents = inverted.attribute(Mesh::ATTRIBUTE_TANGENT).unwrap().clone();
+        inverted.generate_tangents().unwrap();
+        let (
+            VertexAttributeValues::Float32x4(tangents),
+            Some(VertexAttributeValues::Float32x4(generated)),
+        ) = (tangents, inverted.attribute(Mesh::ATTRIBUTE_TANGENT))
+        else {
+            panic!("unexpected tangent format");
+        };
+        for (tangent, generated) in tangents.iter().zip(generated) {
+            assert!(Vec4::from(*tangent).abs_diff_eq(Vec4::from(*generated), 1e-5));
+        }
+
+        let mut lines = Mesh::new(PrimitiveTopology::LineList);
+        assert_eq!(
+            lines.invert_winding(),
+            Err(MeshWindingInversionError::UnsupportedTopology(
+                PrimitiveTopology::LineList
+            ))
+        );
+    }
 }
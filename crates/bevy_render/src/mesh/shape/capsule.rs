@@ -17,6 +17,8 @@ pub struct Capsule {
     pub longitudes: usize,
     /// Manner in which UV coordinates are distributed vertically.
     pub uv_profile: CapsuleUvProfile,
+    /// Whether to add [`Mesh::ATTRIBUTE_TANGENT`] to the mesh, for normal mapping.
+    pub generate_tangents: bool,
 }
 impl Default for Capsule {
     fn default() -> Self {
@@ -27,10 +29,19 @@ impl Default for Capsule {
             latitudes: 16,
             longitudes: 32,
             uv_profile: CapsuleUvProfile::Aspect,
+            generate_tangents: false,
         }
     }
 }
 
+impl Capsule {
+    /// Adds [`Mesh::ATTRIBUTE_TANGENT`] to the mesh, for normal mapping.
+    pub fn with_generated_tangents(mut self) -> Self {
+        self.generate_tangents = true;
+        self
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy)]
 /// Manner in which UV coordinates are distributed vertically.
 pub enum CapsuleUvProfile {
@@ -56,6 +67,7 @@ impl From<Capsule> for Mesh {
             latitudes,
             longitudes,
             uv_profile,
+            generate_tangents,
         } = capsule;
 
         let calc_middle = rings > 0;
@@ -357,6 +369,31 @@ impl From<Capsule> for Mesh {
             i += 1;
         }
 
+        // The u coordinate decreases with the azimuth, so the tangents point clockwise around the
+        // Y axis when seen from above, and v increases upwards. The poles have a vertex for each
+        // longitude, which gets the tangent in the middle of its triangle.
+        let vtans: Vec<[f32; 4]> = if generate_tangents {
+            vns.iter()
+                .enumerate()
+                .map(|(idx, normal)| {
+                    let pole_longitude = if idx < longitudes {
+                        Some(idx)
+                    } else {
+                        idx.checked_sub(vert_offset_south_cap)
+                    };
+                    let du = if let Some(j) = pole_longitude {
+                        let (sin, cos) = ((j as f32 + 0.5) * to_theta).sin_cos();
+                        Vec3::new(sin, 0.0, cos)
+                    } else {
+                        Vec3::new(-normal.z, 0.0, normal.x)
+                    };
+                    super::tangent(*normal, du, Vec3::Y)
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
         let vs: Vec<[f32; 3]> = vs.into_iter().map(Into::into).collect();
         let vns: Vec<[f32; 3]> = vns.into_iter().map(Into::into).collect();
         let vts: Vec<[f32; 2]> = vts.into_iter().map(Into::into).collect();
@@ -368,6 +405,9 @@ impl From<Capsule> for Mesh {
         mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vs);
         mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, vns);
         mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, vts);
+        if generate_tangents {
+            mesh.insert_attribute(Mesh::ATTRIBUTE_TANGENT, vtans);
+        }
         mesh.set_indices(Some(Indices::U32(tris)));
         mesh
     }
@@ -0,0 +1,290 @@
+use crate::mesh::{Indices, Mesh};
+use bevy_math::Vec3;
+use wgpu::PrimitiveTopology;
+
+/// A cone which stands on the XZ plane, with its apex pointing up along the Y axis.
+#[derive(Debug, Clone, Copy)]
+pub struct Cone {
+    /// Radius of the base in the XZ plane.
+    pub radius: f32,
+    /// Height of the cone in the Y axis, from the base to the apex.
+    pub height: f32,
+    /// The number of vertices around the base of the cone.
+    /// A higher number will make it appear more circular.
+    pub resolution: u32,
+    /// The number of segments between the base and the apex.
+    pub segments: u32,
+}
+
+impl Default for Cone {
+    fn default() -> Self {
+        Self {
+            radius: 0.5,
+            height: 1.0,
+            resolution: 32,
+            segments: 1,
+        }
+    }
+}
+
+impl From<Cone> for Mesh {
+    fn from(cone: Cone) -> Self {
+        ConicalFrustum {
+            radius_top: 0.0,
+            radius_bottom: cone.radius,
+            height: cone.height,
+            resolution: cone.resolution,
+            segments: cone.segments,
+        }
+        .into()
+    }
+}
+
+/// A cone with its tip cut off, which stands on the XZ plane.
+///
+/// If the top radius is zero, this is a [`Cone`].
+#[derive(Debug, Clone, Copy)]
+pub struct ConicalFrustum {
+    /// Radius of the top in the XZ plane.
+    pub radius_top: f32,
+    /// Radius of the bottom in the XZ plane.
+    pub radius_bottom: f32,
+    /// Height of the frustum in the Y axis.
+    pub height: f32,
+    /// The number of vertices around each horizontal slice of the frustum.
+    /// A higher number will make it appear more circular.
+    pub resolution: u32,
+    /// The number of segments between the two ends.
+    pub segments: u32,
+}
+
+impl Default for ConicalFrustum {
+    fn default() -> Self {
+        Self {
+            radius_top: 0.25,
+            radius_bottom: 0.5,
+            height: 1.0,
+            resolution: 32,
+            segments: 1,
+        }
+    }
+}
+
+impl From<ConicalFrustum> for Mesh {
+    fn from(f: ConicalFrustum) -> Self {
+        debug_assert!(f.radius_top >= 0.0);
+        debug_assert!(f.radius_bottom > 0.0);
+        debug_assert!(f.height > 0.0);
+        debug_assert!(f.resolution > 2);
+        debug_assert!(f.segments > 0);
+
+        // A cone has a single apex instead of a top ring and cap.
+        let is_cone = f.radius_top == 0.0;
+
+        let num_rings = f.segments + 1;
+        let num_side_vertices = if is_cone {
+            f.segments * (f.resolution + 1) + f.resolution
+        } else {
+            num_rings * (f.resolution + 1)
+        };
+        let num_caps = if is_cone { 1 } else { 2 };
+        let num_vertices = num_side_vertices + num_caps * f.resolution;
+        let num_side_triangles = if is_cone {
+            f.resolution * (2 * f.segments - 1)
+        } else {
+            f.resolution * 2 * f.segments
+        };
+        let num_indices = (num_side_triangles + num_caps * (f.resolution - 2)) * 3;
+
+        let mut positions = Vec::with_capacity(num_vertices as usize);
+        let mut normals = Vec::with_capacity(num_vertices as usize);
+        let mut uvs = Vec::with_capacity(num_vertices as usize);
+        let mut indices = Vec::with_capacity(num_indices as usize);
+
+        let step_theta = std::f32::consts::TAU / f.resolution as f32;
+        let step_y = f.height / f.segments as f32;
+        let step_radius = (f.radius_top - f.radius_bottom) / f.segments as f32;
+
+        // The normals of the slanted side are perpendicular to the line from the bottom to the top.
+        let slant_normal = |theta: f32| {
+            let (sin, cos) = theta.sin_cos();
+            Vec3::new(
+                f.height * cos,
+                f.radius_bottom - f.radius_top,
+                f.height * sin,
+            )
+            .normalize()
+            .to_array()
+        };
+
+        // rings
+
+        for ring in 0..num_rings {
+            let y = -f.height / 2.0 + ring as f32 * step_y;
+            let radius = f.radius_bottom + ring as f32 * step_radius;
+
+            if is_cone && ring == f.segments {
+                // Each triangle touching the apex gets its own apex vertex, with a normal in the
+                // middle of the triangle to avoid shading artifacts.
+                for segment in 0..f.resolution {
+                    let theta = (segment as f32 + 0.5) * step_theta;
+                    positions.push([0.0, y, 0.0]);
+                    normals.push(slant_normal(theta));
+                    uvs.push([(segment as f32 + 0.5) / f.resolution as f32, 1.0]);
+                }
+                continue;
+            }
+
+            for segment in 0..=f.resolution {
+                let theta = segment as f32 * step_theta;
+                let (sin, cos) = theta.sin_cos();
+
+                positions.push([radius * cos, y, radius * sin]);
+                normals.push(slant_normal(theta));
+                uvs.push([
+                    segment as f32 / f.resolution as f32,
+                    ring as f32 / f.segments as f32,
+                ]);
+            }
+        }
+
+        // side
+
+        for i in 0..f.segments {
+            let ring = i * (f.resolution + 1);
+            let next_ring = (i + 1) * (f.resolution + 1);
+
+            for j in 0..f.resolution {
+                if is_cone && i == f.segments - 1 {
+                    indices.extend_from_slice(&[ring + j, next_ring + j, ring + j + 1]);
+                    continue;
+                }
+                indices.extend_from_slice(&[
+                    ring + j,
+                    next_ring + j,
+                    ring + j + 1,
+                    next_ring + j,
+                    next_ring + j + 1,
+                    ring + j + 1,
+                ]);
+            }
+        }
+
+        // caps
+
+        let mut build_cap = |top: bool| {
+            let offset = positions.len() as u32;
+            let (y, radius, normal_y, winding) = if top {
+                (f.height / 2., f.radius_top, 1., (1, 0))
+            } else {
+                (f.height / -2., f.radius_bottom, -1., (0, 1))
+            };
+
+            for i in 0..f.resolution {
+                let theta = i as f32 * step_theta;
+                let (sin, cos) = theta.sin_cos();
+
+                positions.push([cos * radius, y, sin * radius]);
+                normals.push([0.0, normal_y, 0.0]);
+                uvs.push([0.5 * (cos + 1.0), 1.0 - 0.5 * (sin + 1.0)]);
+            }
+
+            for i in 1..(f.resolution - 1) {
+                indices.extend_from_slice(&[
+                    offset,
+                    offset + i + winding.0,
+                    offset + i + winding.1,
+                ]);
+            }
+        };
+
+        if !is_cone {
+            build_cap(true);
+        }
+        build_cap(false);
+
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        mesh.set_indices(Some(Indices::U32(indices)));
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+        mesh
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cone, ConicalFrustum};
+    use crate::mesh::{Indices, Mesh};
+    use bevy_math::Vec3;
+
+    fn float3(mesh: &Mesh, attribute: crate::mesh::MeshVertexAttribute) -> Vec<Vec3> {
+        let values = mesh.attribute(attribute).unwrap().as_float3().unwrap();
+        values.iter().copied().map(Vec3::from).collect()
+    }
+
+    /// Checks that all triangles face outwards and that the side normals are perpendicular to
+    /// the slanted edges.
+    fn check_mesh(mesh: &Mesh, radius_top: f32, radius_bottom: f32, height: f32) {
+        let positions = float3(mesh, Mesh::ATTRIBUTE_POSITION);
+        let normals = float3(mesh, Mesh::ATTRIBUTE_NORMAL);
+        let Some(Indices::U32(indices)) = mesh.indices() else {
+            panic!("missing indices");
+        };
+
+        // The shape is convex, so faces point away from any point inside it.
+        let inside = Vec3::new(0.0, -height / 4.0, 0.0);
+        for triangle in indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| positions[triangle[i] as usize]);
+            let face_normal = (b - a).cross(c - a);
+            assert!(face_normal.length() > 0.0, "degenerate triangle");
+            assert!(face_normal.dot((a + b + c) / 3.0 - inside) > 0.0);
+        }
+
+        for (position, normal) in positions.iter().zip(&normals) {
+            assert!((normal.length() - 1.0).abs() < 1e-5);
+            if normal.y.abs() == 1.0 {
+                continue;
+            }
+            // The slanted edge of the side through this vertex.
+            let direction = Vec3::new(position.x, 0.0, position.z)
+                .try_normalize()
+                .unwrap_or(Vec3::new(normal.x, 0.0, normal.z).normalize());
+            let edge = direction * (radius_top - radius_bottom) + Vec3::Y * height;
+            assert!(normal.dot(edge).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn cone_mesh() {
+        let cone = Cone {
+            radius: 1.0,
+            height: 2.0,
+            resolution: 8,
+            segments: 3,
+        };
+        let mesh = Mesh::from(cone);
+        // 3 rings with a seam vertex, separate apex vertices and the base cap
+        assert_eq!(mesh.count_vertices(), 3 * 9 + 8 + 8);
+        // 2 bands of quads, a band of triangles at the apex, and the base cap
+        assert_eq!(mesh.indices().unwrap().len(), (8 * 2 * 2 + 8 + 6) * 3);
+        check_mesh(&mesh, 0.0, 1.0, 2.0);
+    }
+
+    #[test]
+    fn conical_frustum_mesh() {
+        let frustum = ConicalFrustum {
+            radius_top: 0.5,
+            radius_bottom: 1.5,
+            height: 1.0,
+            resolution: 6,
+            segments: 2,
+        };
+        let mesh = Mesh::from(frustum);
+        // 3 rings with a seam vertex and two caps
+        assert_eq!(mesh.count_vertices(), 3 * 7 + 2 * 6);
+        // 2 bands of quads and two caps
+        assert_eq!(mesh.indices().unwrap().len(), (6 * 2 * 2 + 2 * 4) * 3);
+        check_mesh(&mesh, 0.5, 1.5, 1.0);
+    }
+}
@@ -1,7 +1,9 @@
 use crate::mesh::{Indices, Mesh};
+use bevy_math::Vec3;
 use wgpu::PrimitiveTopology;
 
 /// A cylinder which stands on the XZ plane
+#[derive(Debug, Clone, Copy)]
 pub struct Cylinder {
     /// Radius in the XZ plane.
     pub radius: f32,
@@ -15,6 +17,31 @@ pub struct Cylinder {
     /// height of the cylinder. Setting it to 2 will have two sets of triangles with a horizontal slice in the middle of
     /// cylinder. Greater numbers increase triangles/slices in the same way.
     pub segments: u32,
+    /// Whether to close the cylinder with flat faces: the top and bottom caps, and for partial
+    /// cylinders, the two sides of the cut. Open cylinders are useful for pipes and wells.
+    pub caps: bool,
+    /// The angle in radians where the cylinder starts, from the `+X` axis towards `+Z`.
+    pub start_angle: f32,
+    /// The angle in radians that the cylinder sweeps around its axis.
+    ///
+    /// The default is a full turn. Smaller angles build a partial cylinder, where `resolution` is
+    /// the number of vertices along the arc minus one.
+    pub sweep_angle: f32,
+    /// How UV coordinates are assigned to the side of the cylinder.
+    pub side_uvs: CylinderSideUvs,
+    /// Whether to add [`Mesh::ATTRIBUTE_TANGENT`] to the mesh, for normal mapping.
+    pub generate_tangents: bool,
+}
+
+/// How UV coordinates are assigned to the side of a [`Cylinder`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CylinderSideUvs {
+    /// The texture wraps around the cylinder once.
+    #[default]
+    Wrapped,
+    /// Each face around the cylinder gets the full width of the texture. This needs separate
+    /// vertices for each face.
+    PerFace,
 }
 
 impl Default for Cylinder {
@@ -24,10 +51,23 @@ impl Default for Cylinder {
             height: 1.0,
             resolution: 16,
             segments: 1,
+            caps: true,
+            start_angle: 0.0,
+            sweep_angle: std::f32::consts::TAU,
+            side_uvs: CylinderSideUvs::Wrapped,
+            generate_tangents: false,
         }
     }
 }
 
+impl Cylinder {
+    /// Adds [`Mesh::ATTRIBUTE_TANGENT`] to the mesh, for normal mapping.
+    pub fn with_generated_tangents(mut self) -> Self {
+        self.generate_tangents = true;
+        self
+    }
+}
+
 impl From<Cylinder> for Mesh {
     fn from(c: Cylinder) -> Self {
         debug_assert!(c.radius > 0.0);
@@ -35,55 +75,89 @@ impl From<Cylinder> for Mesh {
         debug_assert!(c.resolution > 2);
         debug_assert!(c.segments > 0);
 
+        let is_full_turn = c.sweep_angle >= std::f32::consts::TAU;
+
+        // The vertices of each ring and their u coordinates, and the pairs of them forming faces.
+        let columns: Vec<(u32, f32)> = match c.side_uvs {
+            CylinderSideUvs::Wrapped => (0..=c.resolution)
+                .map(|segment| (segment, segment as f32 / c.resolution as f32))
+                .collect(),
+            CylinderSideUvs::PerFace => (0..c.resolution)
+                .flat_map(|segment| [(segment, 0.0), (segment + 1, 1.0)])
+                .collect(),
+        };
+        let faces: Vec<(u32, u32)> = match c.side_uvs {
+            CylinderSideUvs::Wrapped => (0..c.resolution).map(|j| (j, j + 1)).collect(),
+            CylinderSideUvs::PerFace => (0..c.resolution).map(|j| (2 * j, 2 * j + 1)).collect(),
+        };
+        let ring_len = columns.len() as u32;
+
         let num_rings = c.segments + 1;
-        let num_vertices = c.resolution * 2 + num_rings * (c.resolution + 1);
-        let num_faces = c.resolution * (num_rings - 2);
-        let num_indices = (2 * num_faces + 2 * (c.resolution - 1) * 2) * 3;
+        let num_cap_vertices = if is_full_turn {
+            2 * c.resolution
+        } else {
+            2 * (c.resolution + 2) + 8
+        };
+        let num_vertices = num_rings * ring_len + if c.caps { num_cap_vertices } else { 0 };
+        let num_cap_triangles = if is_full_turn {
+            2 * (c.resolution - 2)
+        } else {
+            2 * c.resolution + 4
+        };
+        let num_triangles =
+            2 * c.resolution * c.segments + if c.caps { num_cap_triangles } else { 0 };
+        let num_indices = num_triangles * 3;
 
         let mut positions = Vec::with_capacity(num_vertices as usize);
         let mut normals = Vec::with_capacity(num_vertices as usize);
         let mut uvs = Vec::with_capacity(num_vertices as usize);
+        let mut tangents = Vec::new();
         let mut indices = Vec::with_capacity(num_indices as usize);
 
-        let step_theta = std::f32::consts::TAU / c.resolution as f32;
+        let step_theta = c.sweep_angle.min(std::f32::consts::TAU) / c.resolution as f32;
         let step_y = c.height / c.segments as f32;
+        let theta = |segment: u32| c.start_angle + segment as f32 * step_theta;
 
         // rings
 
         for ring in 0..num_rings {
             let y = -c.height / 2.0 + ring as f32 * step_y;
 
-            for segment in 0..=c.resolution {
-                let theta = segment as f32 * step_theta;
-                let (sin, cos) = theta.sin_cos();
+            for &(segment, u) in &columns {
+                let (sin, cos) = theta(segment).sin_cos();
 
                 positions.push([c.radius * cos, y, c.radius * sin]);
                 normals.push([cos, 0., sin]);
-                uvs.push([
-                    segment as f32 / c.resolution as f32,
-                    ring as f32 / c.segments as f32,
-                ]);
+                uvs.push([u, ring as f32 / c.segments as f32]);
+                if c.generate_tangents {
+                    let du = Vec3::new(-sin, 0.0, cos);
+                    tangents.push(super::tangent(Vec3::new(cos, 0.0, sin), du, Vec3::Y));
+                }
             }
         }
 
         // barrel skin
 
         for i in 0..c.segments {
-            let ring = i * (c.resolution + 1);
-            let next_ring = (i + 1) * (c.resolution + 1);
+            let ring = i * ring_len;
+            let next_ring = (i + 1) * ring_len;
 
-            for j in 0..c.resolution {
+            for &(j, k) in &faces {
                 indices.extend_from_slice(&[
                     ring + j,
                     next_ring + j,
-                    ring + j + 1,
+                    ring + k,
                     next_ring + j,
-                    next_ring + j + 1,
-                    ring + j + 1,
+                    next_ring + k,
+                    ring + k,
                 ]);
             }
         }
 
+        if !c.caps {
+            return build_mesh(positions, normals, uvs, tangents, indices);
+        }
+
         // caps
 
         let mut build_cap = |top: bool| {
@@ -93,22 +167,52 @@ impl From<Cylinder> for Mesh {
             } else {
                 (c.height / -2., -1., (0, 1))
             };
+            // The UVs of both caps look at them from above, with v increasing towards -Z.
+            let tangent = super::tangent(Vec3::new(0.0, normal_y, 0.0), Vec3::X, Vec3::NEG_Z);
 
-            for i in 0..c.resolution {
-                let theta = i as f32 * step_theta;
-                let (sin, cos) = theta.sin_cos();
+            if is_full_turn {
+                for i in 0..c.resolution {
+                    let (sin, cos) = theta(i).sin_cos();
 
-                positions.push([cos * c.radius, y, sin * c.radius]);
+                    positions.push([cos * c.radius, y, sin * c.radius]);
+                    normals.push([0.0, normal_y, 0.0]);
+                    uvs.push([0.5 * (cos + 1.0), 1.0 - 0.5 * (sin + 1.0)]);
+                }
+                if c.generate_tangents {
+                    tangents.resize(positions.len(), tangent);
+                }
+
+                for i in 1..(c.resolution - 1) {
+                    indices.extend_from_slice(&[
+                        offset,
+                        offset + i + winding.0,
+                        offset + i + winding.1,
+                    ]);
+                }
+            } else {
+                // A partial cap is a circular sector, fanning out from the center.
+                positions.push([0.0, y, 0.0]);
                 normals.push([0.0, normal_y, 0.0]);
-                uvs.push([0.5 * (cos + 1.0), 1.0 - 0.5 * (sin + 1.0)]);
-            }
+                uvs.push([0.5, 0.5]);
 
-            for i in 1..(c.resolution - 1) {
-                indices.extend_from_slice(&[
-                    offset,
-                    offset + i + winding.0,
-                    offset + i + winding.1,
-                ]);
+                for i in 0..=c.resolution {
+                    let (sin, cos) = theta(i).sin_cos();
+
+                    positions.push([cos * c.radius, y, sin * c.radius]);
+                    normals.push([0.0, normal_y, 0.0]);
+                    uvs.push([0.5 * (cos + 1.0), 1.0 - 0.5 * (sin + 1.0)]);
+                }
+                if c.generate_tangents {
+                    tangents.resize(positions.len(), tangent);
+                }
+
+                for i in 1..=c.resolution {
+                    indices.extend_from_slice(&[
+                        offset,
+                        offset + i + winding.0,
+                        offset + i + winding.1,
+                    ]);
+                }
             }
         };
 
@@ -117,11 +221,225 @@ impl From<Cylinder> for Mesh {
         build_cap(true);
         build_cap(false);
 
-        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
-        mesh.set_indices(Some(Indices::U32(indices)));
-        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
-        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
-        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
-        mesh
+        // sides of the cut of a partial cylinder
+
+        if !is_full_turn {
+            for end in [false, true] {
+                let offset = positions.len() as u32;
+                let (sin, cos) = theta(if end { c.resolution } else { 0 }).sin_cos();
+                // The faces point away from the rest of the cylinder.
+                let normal = if end {
+                    [-sin, 0.0, cos]
+                } else {
+                    [sin, 0.0, -cos]
+                };
+                let (bottom, top) = (-c.height / 2.0, c.height / 2.0);
+
+                positions.extend([
+                    [0.0, bottom, 0.0],
+                    [cos * c.radius, bottom, sin * c.radius],
+                    [0.0, top, 0.0],
+                    [cos * c.radius, top, sin * c.radius],
+                ]);
+                normals.extend([normal; 4]);
+                uvs.extend([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+                if c.generate_tangents {
+                    let du = Vec3::new(cos, 0.0, sin);
+                    let tangent = super::tangent(Vec3::from(normal), du, Vec3::Y);
+                    tangents.extend([tangent; 4]);
+                }
+
+                let quad = if end {
+                    [0, 1, 2, 2, 1, 3]
+                } else {
+                    [0, 2, 1, 2, 3, 1]
+                };
+                indices.extend(quad.map(|i| offset + i));
+            }
+        }
+
+        build_mesh(positions, normals, uvs, tangents, indices)
+    }
+}
+
+fn build_mesh(
+    positions: Vec<[f32; 3]>,
+    normals: Vec<[f32; 3]>,
+    uvs: Vec<[f32; 2]>,
+    tangents: Vec<[f32; 4]>,
+    indices: Vec<u32>,
+) -> Mesh {
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+    mesh.set_indices(Some(Indices::U32(indices)));
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    if !tangents.is_empty() {
+        mesh.insert_attribute(Mesh::ATTRIBUTE_TANGENT, tangents);
+    }
+    mesh
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cylinder, CylinderSideUvs};
+    use crate::mesh::{Indices, Mesh, VertexAttributeValues};
+    use bevy_math::{Vec2, Vec3};
+
+    /// Checks the vertex and index counts, and that every triangle faces the same way as the
+    /// normals of its vertices.
+    fn check_mesh(cylinder: Cylinder, vertices: usize, triangles: usize) {
+        let mesh = Mesh::from(cylinder);
+        assert_eq!(mesh.count_vertices(), vertices);
+        let Some(Indices::U32(indices)) = mesh.indices() else {
+            panic!("missing indices");
+        };
+        assert_eq!(indices.len(), triangles * 3);
+
+        let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION).unwrap();
+        let positions = positions.as_float3().unwrap();
+        let normals = mesh.attribute(Mesh::ATTRIBUTE_NORMAL).unwrap();
+        let normals = normals.as_float3().unwrap();
+        for triangle in indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| Vec3::from(positions[triangle[i] as usize]));
+            let face_normal = (b - a).cross(c - a);
+            for i in triangle {
+                assert!(face_normal.dot(Vec3::from(normals[*i as usize])) > 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn default_cylinder() {
+        // 2 rings with a seam vertex, and two caps
+        check_mesh(Cylinder::default(), 2 * 17 + 2 * 16, 2 * 16 + 2 * 14);
+    }
+
+    #[test]
+    fn default_cylinder_layout() {
+        let c = Cylinder {
+            resolution: 7,
+            segments: 3,
+            ..Default::default()
+        };
+
+        // The vertices and indices of a cylinder before open ends and partial cylinders.
+        let mut expected = Vec::new();
+        let mut expected_indices = Vec::new();
+        let step_theta = std::f32::consts::TAU / c.resolution as f32;
+        let step_y = c.height / c.segments as f32;
+        for ring in 0..=c.segments {
+            let y = -c.height / 2.0 + ring as f32 * step_y;
+            for segment in 0..=c.resolution {
+                let (sin, cos) = (segment as f32 * step_theta).sin_cos();
+                expected.push((
+                    [c.radius * cos, y, c.radius * sin],
+                    [cos, 0., sin],
+                    [
+                        segment as f32 / c.resolution as f32,
+                        ring as f32 / c.segments as f32,
+                    ],
+                ));
+            }
+        }
+        for i in 0..c.segments {
+            let ring = i * (c.resolution + 1);
+            let next_ring = (i + 1) * (c.resolution + 1);
+            for j in 0..c.resolution {
+                expected_indices.extend([ring + j, next_ring + j, ring + j + 1]);
+                expected_indices.extend([next_ring + j, next_ring + j + 1, ring + j + 1]);
+            }
+        }
+        for (y, normal_y, winding) in [(c.height / 2., 1., (1, 0)), (c.height / -2., -1., (0, 1))] {
+            let offset = expected.len() as u32;
+            for i in 0..c.resolution {
+                let (sin, cos) = (i as f32 * step_theta).sin_cos();
+                expected.push((
+                    [cos * c.radius, y, sin * c.radius],
+                    [0.0, normal_y, 0.0],
+                    [0.5 * (cos + 1.0), 1.0 - 0.5 * (sin + 1.0)],
+                ));
+            }
+            for i in 1..(c.resolution - 1) {
+                expected_indices.extend([offset, offset + i + winding.0, offset + i + winding.1]);
+            }
+        }
+
+        let mesh = Mesh::from(c);
+        let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION).unwrap();
+        let normals = mesh.attribute(Mesh::ATTRIBUTE_NORMAL).unwrap();
+        let Some(VertexAttributeValues::Float32x2(uvs)) = mesh.attribute(Mesh::ATTRIBUTE_UV_0)
+        else {
+            panic!("unexpected uv format");
+        };
+        assert_eq!(mesh.count_vertices(), expected.len());
+        let vertices = positions
+            .as_float3()
+            .unwrap()
+            .iter()
+            .zip(normals.as_float3().unwrap())
+            .zip(uvs);
+        for (i, ((position, normal), uv)) in vertices.enumerate() {
+            let (expected_position, expected_normal, expected_uv) = expected[i];
+            let close = Vec3::from(*position).abs_diff_eq(expected_position.into(), 1e-6)
+                && Vec3::from(*normal).abs_diff_eq(expected_normal.into(), 1e-6)
+                && Vec2::from(*uv).abs_diff_eq(expected_uv.into(), 1e-6);
+            assert!(close, "vertex {i}");
+        }
+        let Some(Indices::U32(indices)) = mesh.indices() else {
+            panic!("missing indices");
+        };
+        assert_eq!(*indices, expected_indices);
+        assert!(mesh.attribute(Mesh::ATTRIBUTE_TANGENT).is_none());
+    }
+
+    #[test]
+    fn open_cylinder() {
+        let cylinder = Cylinder {
+            resolution: 8,
+            segments: 3,
+            caps: false,
+            ..Default::default()
+        };
+        check_mesh(cylinder, 4 * 9, 3 * 2 * 8);
+    }
+
+    #[test]
+    fn partial_cylinder() {
+        let cylinder = Cylinder {
+            resolution: 4,
+            segments: 2,
+            start_angle: 0.3,
+            sweep_angle: std::f32::consts::FRAC_PI_2,
+            ..Default::default()
+        };
+        // 3 rings, two sector caps with a center vertex, and two quads closing the cut
+        check_mesh(cylinder, 3 * 5 + 2 * 6 + 2 * 4, 2 * 2 * 4 + 2 * 4 + 2 * 2);
+
+        let open = Cylinder {
+            caps: false,
+            ..cylinder
+        };
+        check_mesh(open, 3 * 5, 2 * 2 * 4);
+    }
+
+    #[test]
+    fn per_face_uvs() {
+        let cylinder = Cylinder {
+            resolution: 6,
+            side_uvs: CylinderSideUvs::PerFace,
+            caps: false,
+            ..Default::default()
+        };
+        check_mesh(cylinder, 2 * 12, 2 * 6);
+
+        let mesh = Mesh::from(cylinder);
+        let uvs = mesh.attribute(Mesh::ATTRIBUTE_UV_0).unwrap();
+        let VertexAttributeValues::Float32x2(uvs) = uvs else {
+            panic!("unexpected uv format");
+        };
+        for face in uvs.chunks_exact(2) {
+            assert_eq!((face[0][0], face[1][0]), (0.0, 1.0));
+        }
     }
 }
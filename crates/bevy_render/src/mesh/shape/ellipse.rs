@@ -0,0 +1,179 @@
+use crate::mesh::{Indices, Mesh};
+use wgpu::PrimitiveTopology;
+
+/// An ellipse in the `XY` plane, or an arc of one.
+///
+/// A full ellipse is a fan of triangles from its first vertex. An arc is a fan from an extra
+/// vertex in the center, which makes it a sector of the ellipse, like a slice of a pie chart or a
+/// radial progress indicator.
+#[derive(Debug, Copy, Clone)]
+pub struct Ellipse {
+    /// Half of the width of the ellipse, along the X axis.
+    pub half_width: f32,
+    /// Half of the height of the ellipse, along the Y axis.
+    pub half_height: f32,
+    /// The number of vertices on the border of the ellipse, including both ends of an arc.
+    pub vertices: usize,
+    /// The angle in radians of the first vertex, from the `+X` axis towards `+Y`.
+    ///
+    /// The default starts at the top, like a [`Circle`](super::Circle).
+    pub start_angle: f32,
+    /// The angle in radians covered by the ellipse, counterclockwise from the start angle.
+    ///
+    /// The default is a full turn. Smaller angles make an arc.
+    pub sweep_angle: f32,
+}
+
+impl Default for Ellipse {
+    fn default() -> Self {
+        Self {
+            half_width: 1.0,
+            half_height: 0.5,
+            vertices: 64,
+            start_angle: std::f32::consts::FRAC_PI_2,
+            sweep_angle: std::f32::consts::TAU,
+        }
+    }
+}
+
+impl Ellipse {
+    /// Creates an ellipse in the `XY` plane with the given half width and half height.
+    pub fn new(half_width: f32, half_height: f32) -> Self {
+        Self {
+            half_width,
+            half_height,
+            ..Default::default()
+        }
+    }
+
+    /// Turns the ellipse into an arc covering `sweep_angle` radians counterclockwise from
+    /// `start_angle`.
+    pub fn arc(mut self, start_angle: f32, sweep_angle: f32) -> Self {
+        self.start_angle = start_angle;
+        self.sweep_angle = sweep_angle;
+        self
+    }
+}
+
+impl From<Ellipse> for Mesh {
+    fn from(ellipse: Ellipse) -> Self {
+        let Ellipse {
+            half_width,
+            half_height,
+            vertices,
+            start_angle,
+            sweep_angle,
+        } = ellipse;
+        let is_full = sweep_angle >= std::f32::consts::TAU;
+
+        debug_assert!(sweep_angle > 0.0, "The sweep angle must be positive.");
+        debug_assert!(
+            vertices > if is_full { 2 } else { 1 },
+            "An ellipse requires at least 3 vertices, and an arc at least 2."
+        );
+
+        let border_vertices = vertices;
+        let vertices = if is_full { vertices } else { vertices + 1 };
+        let mut positions = Vec::with_capacity(vertices);
+        let mut uvs = Vec::with_capacity(vertices);
+        let normals = vec![[0.0, 0.0, 1.0]; vertices];
+
+        // The border of a full ellipse doesn't repeat its first vertex.
+        let step = if is_full {
+            std::f32::consts::TAU / border_vertices as f32
+        } else {
+            sweep_angle / (border_vertices - 1) as f32
+        };
+        if !is_full {
+            positions.push([0.0; 3]);
+            uvs.push([0.5, 0.5]);
+        }
+        for i in 0..border_vertices {
+            let theta = start_angle + i as f32 * step;
+            let (sin, cos) = theta.sin_cos();
+
+            positions.push([cos * half_width, sin * half_height, 0.0]);
+            uvs.push([0.5 * (cos + 1.0), 1.0 - 0.5 * (sin + 1.0)]);
+        }
+
+        // Both fan out from vertex 0, which is the first border vertex or the center.
+        let indices: Vec<u32> = (1..vertices as u32 - 1)
+            .flat_map(|i| [0, i, i + 1])
+            .collect();
+
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+        mesh.set_indices(Some(Indices::U32(indices)));
+        mesh
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ellipse;
+    use crate::mesh::{Indices, Mesh};
+    use bevy_math::Vec3;
+    use std::f32::consts::{FRAC_PI_2, TAU};
+
+    /// Checks that the triangles face `+Z` and that the vertices are on the ellipse, except for
+    /// the center of an arc.
+    fn check_mesh(mesh: &Mesh, ellipse: Ellipse) {
+        let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION).unwrap();
+        let positions = positions.as_float3().unwrap();
+        let is_arc = ellipse.sweep_angle < TAU;
+        for (i, [x, y, z]) in positions.iter().enumerate() {
+            assert_eq!(*z, 0.0);
+            if is_arc && i == 0 {
+                assert_eq!([*x, *y], [0.0, 0.0]);
+                continue;
+            }
+            let equation = (x / ellipse.half_width).powi(2) + (y / ellipse.half_height).powi(2);
+            assert!((equation - 1.0).abs() < 1e-5);
+        }
+
+        let Some(Indices::U32(indices)) = mesh.indices() else {
+            panic!("missing indices");
+        };
+        for triangle in indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| Vec3::from(positions[triangle[i] as usize]));
+            assert!((b - a).cross(c - a).z > 0.0);
+        }
+    }
+
+    #[test]
+    fn full_ellipse() {
+        let ellipse = Ellipse {
+            vertices: 32,
+            ..Ellipse::new(2.0, 0.5)
+        };
+        let mesh = Mesh::from(ellipse);
+        assert_eq!(mesh.count_vertices(), 32);
+        // No triangle is repeated at the seam.
+        assert_eq!(mesh.indices().unwrap().len(), 30 * 3);
+        check_mesh(&mesh, ellipse);
+
+        let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION).unwrap();
+        let first = Vec3::from(positions.as_float3().unwrap()[0]);
+        assert!(first.abs_diff_eq(Vec3::new(0.0, 0.5, 0.0), 1e-6));
+    }
+
+    #[test]
+    fn ellipse_arc() {
+        let quarter = Ellipse {
+            vertices: 9,
+            ..Ellipse::new(1.0, 3.0).arc(0.0, FRAC_PI_2)
+        };
+        let mesh = Mesh::from(quarter);
+        // A center vertex and the border
+        assert_eq!(mesh.count_vertices(), 10);
+        assert_eq!(mesh.indices().unwrap().len(), 8 * 3);
+        check_mesh(&mesh, quarter);
+
+        let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION).unwrap();
+        let positions = positions.as_float3().unwrap();
+        assert_eq!(positions[1], [1.0, 0.0, 0.0]);
+        assert!(Vec3::from(positions[9]).abs_diff_eq(Vec3::new(0.0, 3.0, 0.0), 1e-6));
+    }
+}
@@ -4,6 +4,14 @@ use thiserror::Error;
 use wgpu::PrimitiveTopology;
 
 /// A sphere made from a subdivided Icosahedron.
+///
+/// Unlike a [`UVSphere`](super::UVSphere), the vertices are spread evenly over the surface.
+///
+/// The UVs use an equirectangular projection, but the vertices are shared between all triangles,
+/// including the ones crossing the seam at `-X`. Those triangles stretch backwards over the whole
+/// texture, so the seam is visible with textures that aren't uniform.
+///
+/// Converting to a [`Mesh`] fails with [`FromIcosphereError`] if there are too many subdivisions.
 #[derive(Debug, Clone, Copy)]
 pub struct Icosphere {
     /// The radius of the sphere.
@@ -21,8 +29,10 @@ impl Default for Icosphere {
     }
 }
 
+/// An error when converting an [`Icosphere`] to a [`Mesh`].
 #[derive(Debug, Clone, Error)]
 pub enum FromIcosphereError {
+    /// The icosphere would have more vertices than supported.
     #[error("Cannot create an icosphere of {subdivisions} subdivisions due to there being too many vertices being generated: {number_of_resulting_points}. (Limited to 65535 vertices or 79 subdivisions)")]
     TooManyVertices {
         subdivisions: usize,
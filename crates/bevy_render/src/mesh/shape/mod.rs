@@ -35,6 +35,20 @@ pub struct Box {
 
     pub min_z: f32,
     pub max_z: f32,
+
+    /// How each face of the box is built, in the order front (`+Z`), back (`-Z`), right (`+X`),
+    /// left (`-X`), top (`+Y`) and bottom (`-Y`).
+    pub faces: [BoxFace; 6],
+
+    /// Whether to add [`Mesh::ATTRIBUTE_TANGENT`] to the mesh, for normal mapping.
+    pub generate_tangents: bool,
+
+    /// Whether the faces point into the box, so that it is visible from the inside, for example
+    /// for a room or a skybox.
+    ///
+    /// The texture of each face is mirrored horizontally, so that it reads the same from the
+    /// inside as the outside of a regular box.
+    pub inside_out: bool,
 }
 
 impl Box {
@@ -47,6 +61,9 @@ impl Box {
             min_y: -y_length / 2.0,
             max_z: z_length / 2.0,
             min_z: -z_length / 2.0,
+            faces: [BoxFace::default(); 6],
+            generate_tangents: false,
+            inside_out: false,
         }
     }
 
@@ -61,8 +78,23 @@ impl Box {
             min_y: min.y,
             max_z: max.z,
             min_z: min.z,
+            faces: [BoxFace::default(); 6],
+            generate_tangents: false,
+            inside_out: false,
         }
     }
+
+    /// Adds [`Mesh::ATTRIBUTE_TANGENT`] to the mesh, for normal mapping.
+    pub fn with_generated_tangents(mut self) -> Self {
+        self.generate_tangents = true;
+        self
+    }
+
+    /// Turns the faces of the box inwards, so that it is visible from the inside.
+    pub fn inside_out(mut self) -> Self {
+        self.inside_out = true;
+        self
+    }
 }
 
 impl Default for Box {
@@ -71,10 +103,38 @@ impl Default for Box {
     }
 }
 
+/// How a face of a [`Box`] is built.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BoxFace {
+    /// The area of the texture shown on the face, in UV coordinates.
+    ///
+    /// Use this to pick a tile of a texture atlas or trim sheet.
+    pub uv_rect: Rect,
+    /// Whether to mirror the texture horizontally on this face.
+    pub flip_x: bool,
+    /// Whether to mirror the texture vertically on this face.
+    pub flip_y: bool,
+    /// The number of columns and rows of quads the face is divided into.
+    ///
+    /// This adds vertices inside the face, for example for vertex displacement.
+    pub subdivisions: UVec2,
+}
+
+impl Default for BoxFace {
+    fn default() -> Self {
+        Self {
+            uv_rect: Rect::new(0.0, 0.0, 1.0, 1.0),
+            flip_x: false,
+            flip_y: false,
+            subdivisions: UVec2::ONE,
+        }
+    }
+}
+
 impl From<Box> for Mesh {
     fn from(sp: Box) -> Self {
         // suppose Y-up right hand, and camera look from +z to -z
-        let vertices = &[
+        let corners = &[
             // Front
             ([sp.min_x, sp.min_y, sp.max_z], [0., 0., 1.0], [0., 0.]),
             ([sp.max_x, sp.min_y, sp.max_z], [0., 0., 1.0], [1.0, 0.]),
@@ -107,24 +167,109 @@ impl From<Box> for Mesh {
             ([sp.max_x, sp.min_y, sp.min_z], [0., -1.0, 0.], [0., 1.0]),
         ];
 
-        let positions: Vec<_> = vertices.iter().map(|(p, _, _)| *p).collect();
-        let normals: Vec<_> = vertices.iter().map(|(_, n, _)| *n).collect();
-        let uvs: Vec<_> = vertices.iter().map(|(_, _, uv)| *uv).collect();
+        // Exact at both ends, and constant if `a == b`, which keeps the faces planar.
+        fn lerp<const N: usize>(a: [f32; N], b: [f32; N], t: f32) -> [f32; N] {
+            if t == 1.0 {
+                return b;
+            }
+            std::array::from_fn(|i| a[i] + (b[i] - a[i]) * t)
+        }
+
+        let mut positions = Vec::new();
+        let mut normals = Vec::new();
+        let mut uvs = Vec::new();
+        let mut tangents = Vec::new();
+        let mut indices = Vec::new();
+
+        for (face, corners) in sp.faces.iter().zip(corners.chunks_exact(4)) {
+            debug_assert!(face.subdivisions.cmpgt(UVec2::ZERO).all());
+            let offset = positions.len() as u32;
+            let (columns, rows) = (face.subdivisions.x, face.subdivisions.y);
+            let &[(p0, normal, uv0), (p1, _, uv1), (p2, _, uv2), (p3, _, uv3)] = corners else {
+                unreachable!();
+            };
+            let normal = if sp.inside_out {
+                (-Vec3::from(normal)).to_array()
+            } else {
+                normal
+            };
+            let face_uv = |[mut u, mut v]: [f32; 2]| {
+                if face.flip_x != sp.inside_out {
+                    u = 1.0 - u;
+                }
+                if face.flip_y {
+                    v = 1.0 - v;
+                }
+                face.uv_rect.min + Vec2::new(u, v) * face.uv_rect.size()
+            };
+
+            if sp.generate_tangents {
+                // The UVs are an affine function of the position on the face, so its edges give
+                // the directions in which they increase.
+                let (edge_1, edge_2) = (
+                    Vec3::from(p1) - Vec3::from(p0),
+                    Vec3::from(p3) - Vec3::from(p0),
+                );
+                let (uv_1, uv_2) = (face_uv(uv1) - face_uv(uv0), face_uv(uv3) - face_uv(uv0));
+                let det = uv_1.perp_dot(uv_2);
+                let du = (edge_1 * uv_2.y - edge_2 * uv_1.y) * det;
+                let dv = (edge_2 * uv_1.x - edge_1 * uv_2.x) * det;
+                let tangent = tangent(Vec3::from(normal), du, dv);
+                let face_vertices = ((columns + 1) * (rows + 1)) as usize;
+                tangents.resize(tangents.len() + face_vertices, tangent);
+            }
+
+            // Rows alternate direction, so that a single quad keeps the vertex order of the
+            // corners.
+            let grid_column = |column: u32, row: u32| {
+                if row % 2 == 0 {
+                    column
+                } else {
+                    columns - column
+                }
+            };
+            let index = |column, row| offset + row * (columns + 1) + grid_column(column, row);
 
-        let indices = Indices::U32(vec![
-            0, 1, 2, 2, 3, 0, // front
-            4, 5, 6, 6, 7, 4, // back
-            8, 9, 10, 10, 11, 8, // right
-            12, 13, 14, 14, 15, 12, // left
-            16, 17, 18, 18, 19, 16, // top
-            20, 21, 22, 22, 23, 20, // bottom
-        ]);
+            for row in 0..=rows {
+                let t = row as f32 / rows as f32;
+                for column in 0..=columns {
+                    let s = grid_column(column, row) as f32 / columns as f32;
+
+                    positions.push(lerp(lerp(p0, p1, s), lerp(p3, p2, s), t));
+                    normals.push(normal);
+
+                    let uv = face_uv(lerp(lerp(uv0, uv1, s), lerp(uv3, uv2, s), t));
+                    uvs.push(uv.to_array());
+                }
+            }
+
+            for row in 0..rows {
+                for column in 0..columns {
+                    let quad = [
+                        index(column, row),
+                        index(column + 1, row),
+                        index(column + 1, row + 1),
+                        index(column + 1, row + 1),
+                        index(column, row + 1),
+                        index(column, row),
+                    ];
+                    if sp.inside_out {
+                        indices.extend(quad.iter().rev());
+                    } else {
+                        indices.extend_from_slice(&quad);
+                    }
+                }
+            }
+        }
 
         let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
         mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
         mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
         mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
-        mesh.set_indices(Some(indices));
+        if sp.generate_tangents {
+            mesh.insert_attribute(Mesh::ATTRIBUTE_TANGENT, tangents);
+        }
+        mesh.set_indices(Some(Indices::U32(indices)));
         mesh
     }
 }
@@ -187,23 +332,61 @@ impl From<Quad> for Mesh {
 pub struct Plane {
     /// The total side length of the square.
     pub size: f32,
-    /// The number of subdivisions in the mesh.
+    /// The number of lines splitting the plane along the X axis.
     ///
-    /// 0 - is the original plane geometry, the 4 points in the XZ plane.
+    /// 0 - is the original plane geometry, a single quad along the X axis.
     ///
-    /// 1 - is split by 1 line in the middle of the plane on both the X axis and the Z axis, resulting in a plane with 4 quads / 8 triangles.
+    /// 1 - is split by 1 line in the middle of the plane, resulting in 2 quads along the X axis.
     ///
-    /// 2 - is a plane split by 2 lines on both the X and Z axes, subdividing the plane into 3 equal sections along each axis, resulting in a plane with 9 quads / 18 triangles.
+    /// 2 - is split by 2 lines, resulting in 3 equal sections along the X axis.
     ///
     /// and so on...
-    pub subdivisions: u32,
+    ///
+    /// Use [`Plane::subdivisions`] to subdivide both axes the same way.
+    pub subdivisions_x: u32,
+    /// The number of lines splitting the plane along the Z axis, like
+    /// [`subdivisions_x`](Self::subdivisions_x).
+    pub subdivisions_z: u32,
+    /// The scale of the UV coordinates, which span `0.0..1.0` over the plane by default.
+    ///
+    /// Values above 1 repeat a texture with [`AddressMode::Repeat`] over the plane.
+    ///
+    /// [`AddressMode::Repeat`]: crate::render_resource::AddressMode::Repeat
+    pub uv_scale: Vec2,
+    /// The offset added to the UV coordinates after scaling them.
+    pub uv_offset: Vec2,
+    /// The depth of the skirt hanging down from the border of the plane, or 0 for no skirt.
+    ///
+    /// A skirt hides the cracks between adjacent tiles of terrain or water whose heights don't
+    /// match exactly. Its top edge shares the positions of the plane's border vertices.
+    pub skirt_depth: f32,
+    /// How the skirt is textured.
+    pub skirt_uvs: PlaneSkirtUvs,
+    /// Whether to add [`Mesh::ATTRIBUTE_TANGENT`] to the mesh, for normal mapping.
+    pub generate_tangents: bool,
+}
+
+/// How the skirt of a [`Plane`] is textured.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum PlaneSkirtUvs {
+    /// The skirt repeats the UVs of the plane's border, stretching the border texels downwards.
+    #[default]
+    Clamped,
+    /// The UVs continue past the border as if the skirt was folded out into the plane.
+    Extended,
 }
 
 impl Default for Plane {
     fn default() -> Self {
         Plane {
             size: 1.0,
-            subdivisions: 0,
+            subdivisions_x: 0,
+            subdivisions_z: 0,
+            uv_scale: Vec2::ONE,
+            uv_offset: Vec2::ZERO,
+            skirt_depth: 0.0,
+            skirt_uvs: PlaneSkirtUvs::Clamped,
+            generate_tangents: false,
         }
     }
 }
@@ -213,33 +396,65 @@ impl Plane {
     pub fn from_size(size: f32) -> Self {
         Self {
             size,
-            subdivisions: 0,
+            ..Default::default()
         }
     }
+
+    /// Sets the number of subdivisions along both the X and Z axes.
+    pub fn subdivisions(mut self, subdivisions: u32) -> Self {
+        self.subdivisions_x = subdivisions;
+        self.subdivisions_z = subdivisions;
+        self
+    }
+
+    /// Adds [`Mesh::ATTRIBUTE_TANGENT`] to the mesh, for normal mapping.
+    pub fn with_generated_tangents(mut self) -> Self {
+        self.generate_tangents = true;
+        self
+    }
 }
 
 impl From<Plane> for Mesh {
     fn from(plane: Plane) -> Self {
-        // here this is split in the z and x directions if one ever needs asymmetrical subdivision
-        // two Plane struct fields would need to be added instead of the single subdivisions field
-        let z_vertex_count = plane.subdivisions + 2;
-        let x_vertex_count = plane.subdivisions + 2;
-        let num_vertices = (z_vertex_count * x_vertex_count) as usize;
-        let num_indices = ((z_vertex_count - 1) * (x_vertex_count - 1) * 6) as usize;
+        let z_vertex_count = plane.subdivisions_z + 2;
+        let x_vertex_count = plane.subdivisions_x + 2;
+        let has_skirt = plane.skirt_depth != 0.0;
+        // Each of the four sides of the skirt has a top and bottom vertex per border vertex.
+        let skirt_vertex_count = if has_skirt {
+            4 * (x_vertex_count + z_vertex_count)
+        } else {
+            0
+        };
+        let skirt_index_count = if has_skirt {
+            12 * (x_vertex_count + z_vertex_count - 2)
+        } else {
+            0
+        };
+        let num_vertices = (z_vertex_count * x_vertex_count + skirt_vertex_count) as usize;
+        let num_indices =
+            ((z_vertex_count - 1) * (x_vertex_count - 1) * 6 + skirt_index_count) as usize;
         let up = Vec3::Y.to_array();
 
         let mut positions: Vec<[f32; 3]> = Vec::with_capacity(num_vertices);
         let mut normals: Vec<[f32; 3]> = Vec::with_capacity(num_vertices);
         let mut uvs: Vec<[f32; 2]> = Vec::with_capacity(num_vertices);
+        let mut tangents: Vec<[f32; 4]> = Vec::new();
         let mut indices: Vec<u32> = Vec::with_capacity(num_indices);
 
+        // The UVs increase along the scaled axes, which may point either way.
+        let (du, dv) = (Vec3::X * plane.uv_scale.x, Vec3::Z * plane.uv_scale.y);
+        if plane.generate_tangents {
+            let tangent = tangent(Vec3::Y, du, dv);
+            tangents.resize((z_vertex_count * x_vertex_count) as usize, tangent);
+        }
+
         for z in 0..z_vertex_count {
             for x in 0..x_vertex_count {
                 let tx = x as f32 / (x_vertex_count - 1) as f32;
                 let tz = z as f32 / (z_vertex_count - 1) as f32;
                 positions.push([(-0.5 + tx) * plane.size, 0.0, (-0.5 + tz) * plane.size]);
                 normals.push(up);
-                uvs.push([tx, tz]);
+                uvs.push((Vec2::new(tx, tz) * plane.uv_scale + plane.uv_offset).to_array());
             }
         }
 
@@ -255,26 +470,673 @@ impl From<Plane> for Mesh {
             }
         }
 
+        if has_skirt {
+            // Each side gets its own vertices so that its normals face outwards. The border is
+            // walked in the direction that keeps the skirt's faces counterclockwise from outside,
+            // and the top vertices are copied from the border so that there are no cracks.
+            let (last_x, last_z) = (x_vertex_count - 1, z_vertex_count - 1);
+            let sides: [(Vec3, u32, fn(u32, u32, u32) -> (u32, u32)); 4] = [
+                (Vec3::NEG_Z, last_x, |i, _, _| (i, 0)),
+                (Vec3::X, last_z, |i, last_x, _| (last_x, i)),
+                (Vec3::Z, last_x, |i, last_x, last_z| (last_x - i, last_z)),
+                (Vec3::NEG_X, last_z, |i, _, last_z| (0, last_z - i)),
+            ];
+            for (side_normal, last, border_vertex) in sides {
+                let first = positions.len() as u32;
+
+                for i in 0..=last {
+                    let (x, z) = border_vertex(i, last_x, last_z);
+                    let border = (z * x_vertex_count + x) as usize;
+                    let top = positions[border];
+                    let top_uv = uvs[border];
+                    let bottom = [top[0], top[1] - plane.skirt_depth, top[2]];
+                    let bottom_uv = match plane.skirt_uvs {
+                        PlaneSkirtUvs::Clamped => top_uv,
+                        PlaneSkirtUvs::Extended => {
+                            let offset =
+                                side_normal.xz() * plane.skirt_depth / plane.size * plane.uv_scale;
+                            (Vec2::from(top_uv) + offset).to_array()
+                        }
+                    };
+                    positions.extend([top, bottom]);
+                    normals.extend([side_normal.to_array(); 2]);
+                    uvs.extend([top_uv, bottom_uv]);
+                }
+
+                if plane.generate_tangents {
+                    // The UVs across the skirt are those of the plane folded down along the
+                    // border. Clamped UVs don't change downwards, but use the same tangents so
+                    // that they match at the border.
+                    let fold = |direction: Vec3| {
+                        let outwards = direction.dot(side_normal);
+                        direction - side_normal * outwards + Vec3::NEG_Y * outwards
+                    };
+                    let tangent = tangent(side_normal, fold(du), fold(dv));
+                    tangents.resize(positions.len(), tangent);
+                }
+
+                for i in 0..last {
+                    let top = first + i * 2;
+                    let (bottom, next_top, next_bottom) = (top + 1, top + 2, top + 3);
+                    indices.extend([top, next_top, bottom, next_top, next_bottom, bottom]);
+                }
+            }
+        }
+
         let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
         mesh.set_indices(Some(Indices::U32(indices)));
         mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
         mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
         mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+        if plane.generate_tangents {
+            mesh.insert_attribute(Mesh::ATTRIBUTE_TANGENT, tangents);
+        }
         mesh
     }
 }
 
+/// Returns the value of [`Mesh::ATTRIBUTE_TANGENT`] for a vertex with the given normal, where the
+/// `u` and `v` texture coordinates increase in the directions `du` and `dv` along the surface.
+///
+/// The tangent points along `du`. Its `w` component is the handedness that
+/// [`Mesh::generate_tangents`] would compute, which is `1.0` when the bitangent, the cross product
+/// of the normal and the tangent, points towards decreasing `v`.
+fn tangent(normal: Vec3, du: Vec3, dv: Vec3) -> [f32; 4] {
+    let tangent = (du - normal * normal.dot(du)).normalize_or_zero();
+    let handedness = if normal.cross(tangent).dot(dv) > 0.0 {
+        -1.0
+    } else {
+        1.0
+    };
+    tangent.extend(handedness).to_array()
+}
+
 mod capsule;
+mod cone;
 mod cylinder;
+mod ellipse;
 mod icosphere;
+mod polygon;
+mod polyline;
 mod regular_polygon;
 mod torus;
 mod uvsphere;
 
 pub use capsule::{Capsule, CapsuleUvProfile};
-pub use cylinder::Cylinder;
-pub use icosphere::Icosphere;
+pub use cone::{Cone, ConicalFrustum};
+pub use cylinder::{Cylinder, CylinderSideUvs};
+pub use ellipse::Ellipse;
+pub use icosphere::{FromIcosphereError, Icosphere};
+pub use polygon::{FromPolygonError, Polygon};
+pub use polyline::{Polyline, PolylineRibbon};
 pub use regular_polygon::{Circle, RegularPolygon};
 pub use torus::Torus;
 pub use uvsphere::UVSphere;
 use wgpu::PrimitiveTopology;
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Box, BoxFace, Capsule, Cylinder, FromIcosphereError, Icosphere, Plane, PlaneSkirtUvs,
+        UVSphere,
+    };
+    use crate::mesh::{Indices, Mesh, VertexAttributeValues};
+    use bevy_math::{IVec3, Rect, UVec2, Vec2, Vec3, Vec4};
+    use bevy_utils::HashSet;
+
+    fn positions(mesh: &Mesh) -> &[[f32; 3]] {
+        let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION).unwrap();
+        positions.as_float3().unwrap()
+    }
+
+    fn triangles(mesh: &Mesh) -> Vec<[Vec3; 3]> {
+        let Some(Indices::U32(indices)) = mesh.indices() else {
+            panic!("missing indices");
+        };
+        let positions = positions(mesh);
+        indices
+            .chunks_exact(3)
+            .map(|triangle| [0, 1, 2].map(|i| Vec3::from(positions[triangle[i] as usize])))
+            .collect()
+    }
+
+    /// The normals of the faces of a box, in the order of [`Box::faces`].
+    const BOX_NORMALS: [Vec3; 6] = [
+        Vec3::Z,
+        Vec3::NEG_Z,
+        Vec3::X,
+        Vec3::NEG_X,
+        Vec3::Y,
+        Vec3::NEG_Y,
+    ];
+
+    #[test]
+    fn box_face_uvs() {
+        let mut shape = Box::new(1.0, 2.0, 3.0);
+        for (i, face) in shape.faces.iter_mut().enumerate() {
+            let min = Vec2::new(i as f32 / 6.0, 0.5);
+            face.uv_rect = Rect::from_corners(min, min + Vec2::new(1.0 / 6.0, 0.25));
+        }
+        shape.faces[0].flip_x = true;
+        shape.faces[4].flip_y = true;
+        let mesh = Mesh::from(shape);
+
+        let normals = mesh.attribute(Mesh::ATTRIBUTE_NORMAL).unwrap();
+        let normals = normals.as_float3().unwrap();
+        let Some(VertexAttributeValues::Float32x2(uvs)) = mesh.attribute(Mesh::ATTRIBUTE_UV_0)
+        else {
+            panic!("unexpected uv format");
+        };
+        let Some(Indices::U32(indices)) = mesh.indices() else {
+            panic!("missing indices");
+        };
+        for triangle in indices.chunks_exact(3) {
+            let normal = Vec3::from(normals[triangle[0] as usize]);
+            let face = BOX_NORMALS.iter().position(|n| *n == normal).unwrap();
+            let rect = shape.faces[face].uv_rect;
+            for i in triangle {
+                assert_eq!(normals[*i as usize], normal.to_array());
+                assert!(rect.contains(Vec2::from(uvs[*i as usize])));
+            }
+        }
+
+        // Flipped faces mirror the texture within their rect.
+        let uv_at = |position: [f32; 3], normal: Vec3| {
+            let positions = positions(&mesh);
+            let i = (0..positions.len())
+                .find(|&i| positions[i] == position && Vec3::from(normals[i]) == normal)
+                .unwrap();
+            Vec2::from(uvs[i])
+        };
+        let front = shape.faces[0].uv_rect;
+        assert_eq!(
+            uv_at([-0.5, -1.0, 1.5], Vec3::Z),
+            Vec2::new(front.max.x, front.min.y)
+        );
+        let back = shape.faces[1].uv_rect;
+        assert_eq!(
+            uv_at([-0.5, 1.0, -1.5], Vec3::NEG_Z),
+            Vec2::new(back.max.x, back.min.y)
+        );
+        let top = shape.faces[4].uv_rect;
+        assert_eq!(uv_at([0.5, 1.0, -1.5], Vec3::Y), top.max);
+    }
+
+    #[test]
+    fn default_box_layout() {
+        // The vertices and indices of a box before faces could be customized.
+        let (min, max) = (Vec3::new(-1.0, -2.0, -3.0), Vec3::new(4.0, 5.0, 6.0));
+        let (x0, y0, z0, x1, y1, z1) = (min.x, min.y, min.z, max.x, max.y, max.z);
+        let expected = [
+            // Front
+            ([x0, y0, z1], [0., 0., 1.0], [0., 0.]),
+            ([x1, y0, z1], [0., 0., 1.0], [1.0, 0.]),
+            ([x1, y1, z1], [0., 0., 1.0], [1.0, 1.0]),
+            ([x0, y1, z1], [0., 0., 1.0], [0., 1.0]),
+            // Back
+            ([x0, y1, z0], [0., 0., -1.0], [1.0, 0.]),
+            ([x1, y1, z0], [0., 0., -1.0], [0., 0.]),
+            ([x1, y0, z0], [0., 0., -1.0], [0., 1.0]),
+            ([x0, y0, z0], [0., 0., -1.0], [1.0, 1.0]),
+            // Right
+            ([x1, y0, z0], [1.0, 0., 0.], [0., 0.]),
+            ([x1, y1, z0], [1.0, 0., 0.], [1.0, 0.]),
+            ([x1, y1, z1], [1.0, 0., 0.], [1.0, 1.0]),
+            ([x1, y0, z1], [1.0, 0., 0.], [0., 1.0]),
+            // Left
+            ([x0, y0, z1], [-1.0, 0., 0.], [1.0, 0.]),
+            ([x0, y1, z1], [-1.0, 0., 0.], [0., 0.]),
+            ([x0, y1, z0], [-1.0, 0., 0.], [0., 1.0]),
+            ([x0, y0, z0], [-1.0, 0., 0.], [1.0, 1.0]),
+            // Top
+            ([x1, y1, z0], [0., 1.0, 0.], [1.0, 0.]),
+            ([x0, y1, z0], [0., 1.0, 0.], [0., 0.]),
+            ([x0, y1, z1], [0., 1.0, 0.], [0., 1.0]),
+            ([x1, y1, z1], [0., 1.0, 0.], [1.0, 1.0]),
+            // Bottom
+            ([x1, y0, z1], [0., -1.0, 0.], [0., 0.]),
+            ([x0, y0, z1], [0., -1.0, 0.], [1.0, 0.]),
+            ([x0, y0, z0], [0., -1.0, 0.], [1.0, 1.0]),
+            ([x1, y0, z0], [0., -1.0, 0.], [0., 1.0]),
+        ];
+        let expected_indices: Vec<u32> = (0..6)
+            .flat_map(|face| [0, 1, 2, 2, 3, 0].map(|i| face * 4 + i))
+            .collect();
+
+        let mesh = Mesh::from(Box::from_corners(max, min));
+        let normals = mesh.attribute(Mesh::ATTRIBUTE_NORMAL).unwrap();
+        let Some(VertexAttributeValues::Float32x2(uvs)) = mesh.attribute(Mesh::ATTRIBUTE_UV_0)
+        else {
+            panic!("unexpected uv format");
+        };
+        let vertices: Vec<_> = positions(&mesh)
+            .iter()
+            .zip(normals.as_float3().unwrap())
+            .zip(uvs)
+            .map(|((position, normal), uv)| (*position, *normal, *uv))
+            .collect();
+        assert_eq!(vertices, expected);
+        let Some(Indices::U32(indices)) = mesh.indices() else {
+            panic!("missing indices");
+        };
+        assert_eq!(*indices, expected_indices);
+        assert!(mesh.attribute(Mesh::ATTRIBUTE_TANGENT).is_none());
+    }
+
+    #[test]
+    fn subdivided_box() {
+        let mut shape = Box::from_corners(Vec3::new(-1.0, 0.0, 0.5), Vec3::new(2.0, 0.3, 1.75));
+        let subdivisions = [(3, 2), (1, 1), (4, 4), (1, 5), (2, 1), (7, 3)];
+        for (face, (columns, rows)) in shape.faces.iter_mut().zip(subdivisions) {
+            *face = BoxFace {
+                subdivisions: UVec2::new(columns, rows),
+                ..Default::default()
+            };
+        }
+        let mesh = Mesh::from(shape);
+
+        let quads: u32 = subdivisions.iter().map(|(c, r)| c * r).sum();
+        let vertices: u32 = subdivisions.iter().map(|(c, r)| (c + 1) * (r + 1)).sum();
+        assert_eq!(mesh.count_vertices(), vertices as usize);
+        assert_eq!(mesh.indices().unwrap().len(), quads as usize * 6);
+
+        let normals = mesh.attribute(Mesh::ATTRIBUTE_NORMAL).unwrap();
+        let normals = normals.as_float3().unwrap();
+        let (min, max) = (Vec3::new(-1.0, 0.0, 0.5), Vec3::new(2.0, 0.3, 1.75));
+        let center = (min + max) / 2.0;
+        let mut areas = [0.0; 6];
+        for (position, normal) in positions(&mesh).iter().zip(normals) {
+            // Each vertex lies exactly in the plane of its face.
+            let (position, normal) = (Vec3::from(*position), Vec3::from(*normal));
+            let extent = if normal.max_element() > 0.0 { max } else { min };
+            assert_eq!(position.dot(normal.abs()), extent.dot(normal.abs()));
+        }
+        for [a, b, c] in triangles(&mesh) {
+            let face_normal = (b - a).cross(c - a);
+            let face = BOX_NORMALS
+                .iter()
+                .position(|n| face_normal.normalize().abs_diff_eq(*n, 1e-6))
+                .expect("faces point outwards along an axis");
+            assert!(face_normal.dot((a + b + c) / 3.0 - center) > 0.0);
+            areas[face] += face_normal.length() / 2.0;
+        }
+        // The triangles cover every face without overlapping.
+        let size = max - min;
+        let expected = [size.x * size.y, size.y * size.z, size.x * size.z].map(|a| [a, a]);
+        for (area, expected) in areas.iter().zip(expected.concat()) {
+            assert!((area - expected).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn plane_without_skirt() {
+        let mesh = Mesh::from(Plane::from_size(2.0).subdivisions(1));
+        assert_eq!(mesh.count_vertices(), 9);
+        assert_eq!(mesh.indices().unwrap().len(), 24);
+    }
+
+    #[test]
+    fn plane_asymmetric_subdivisions() {
+        let plane = Plane {
+            subdivisions_x: 2,
+            subdivisions_z: 5,
+            uv_scale: Vec2::new(4.0, 2.0),
+            uv_offset: Vec2::new(0.5, -1.0),
+            ..Plane::from_size(3.0)
+        };
+        let mesh = Mesh::from(plane);
+        // 4 columns and 7 rows of vertices
+        assert_eq!(mesh.count_vertices(), 4 * 7);
+        assert_eq!(mesh.indices().unwrap().len(), 3 * 6 * 6);
+
+        let Some(VertexAttributeValues::Float32x2(uvs)) = mesh.attribute(Mesh::ATTRIBUTE_UV_0)
+        else {
+            panic!("unexpected uv format");
+        };
+        let corners = [0, 3, 4 * 6, 4 * 7 - 1];
+        assert_eq!(
+            corners.map(|i| positions(&mesh)[i]),
+            [
+                [-1.5, 0.0, -1.5],
+                [1.5, 0.0, -1.5],
+                [-1.5, 0.0, 1.5],
+                [1.5, 0.0, 1.5]
+            ]
+        );
+        assert_eq!(
+            corners.map(|i| uvs[i]),
+            [[0.5, -1.0], [4.5, -1.0], [0.5, 1.0], [4.5, 1.0]]
+        );
+
+        // The skirt follows the border on all four sides.
+        let mesh = Mesh::from(Plane {
+            skirt_depth: 0.25,
+            ..plane
+        });
+        assert_eq!(mesh.count_vertices(), 4 * 7 + 2 * 2 * (4 + 7));
+        assert_eq!(mesh.indices().unwrap().len(), 3 * 6 * 6 + 2 * 6 * (3 + 6));
+        let (grid, skirt) = positions(&mesh).split_at(4 * 7);
+        for top in skirt.iter().step_by(2) {
+            assert!(grid.contains(top));
+            assert!(top[0].abs() == 1.5 || top[2].abs() == 1.5);
+        }
+    }
+
+    #[test]
+    fn plane_skirt() {
+        let plane = Plane {
+            skirt_depth: 0.5,
+            skirt_uvs: PlaneSkirtUvs::Extended,
+            ..Plane::from_size(2.0).subdivisions(2)
+        };
+        let mesh = Mesh::from(plane);
+        let grid_vertices = 4 * 4;
+        let grid_indices = 3 * 3 * 6;
+        // Four sides, each with a top and bottom vertex per border vertex.
+        assert_eq!(mesh.count_vertices(), grid_vertices + 4 * 4 * 2);
+        // Four sides, each with a quad per border segment.
+        assert_eq!(mesh.indices().unwrap().len(), grid_indices + 4 * 3 * 6);
+
+        let normals = mesh.attribute(Mesh::ATTRIBUTE_NORMAL).unwrap();
+        let normals = normals.as_float3().unwrap();
+        let (grid, skirt) = positions(&mesh).split_at(grid_vertices);
+        for (i, (top, bottom)) in skirt
+            .iter()
+            .zip(skirt.iter().skip(1))
+            .step_by(2)
+            .enumerate()
+        {
+            // The top of the skirt shares the border's positions exactly.
+            assert!(grid.contains(top), "skirt vertex {i} is not on the border");
+            assert_eq!(bottom[1], top[1] - plane.skirt_depth);
+        }
+        for normal in &normals[grid_vertices..] {
+            assert_eq!(Vec3::from(*normal).dot(Vec3::Y), 0.0);
+        }
+
+        // All skirt faces point outwards.
+        for [a, b, c] in &triangles(&mesh)[grid_indices / 3..] {
+            let face_normal = (*b - *a).cross(*c - *a);
+            let center = (*a + *b + *c) / 3.0;
+            assert!(face_normal.dot(center * Vec3::new(1.0, 0.0, 1.0)) > 0.0);
+        }
+    }
+
+    /// Returns `V - E + F` of the surface, merging vertices at the same position.
+    fn euler_characteristic(mesh: &Mesh) -> i64 {
+        let Some(Indices::U32(indices)) = mesh.indices() else {
+            panic!("missing indices");
+        };
+        let welded: Vec<IVec3> = positions(mesh)
+            .iter()
+            .map(|p| (Vec3::from(*p) * 1e4).round().as_ivec3())
+            .collect();
+        let vertices: HashSet<IVec3> = welded.iter().copied().collect();
+        let mut edges = HashSet::new();
+        for triangle in indices.chunks_exact(3) {
+            for (a, b) in [(0, 1), (1, 2), (2, 0)] {
+                let (a, b) = (welded[triangle[a] as usize], welded[triangle[b] as usize]);
+                assert_ne!(a, b, "degenerate triangle");
+                edges.insert(if a.to_array() < b.to_array() {
+                    (a, b)
+                } else {
+                    (b, a)
+                });
+            }
+        }
+        vertices.len() as i64 - edges.len() as i64 + (indices.len() / 3) as i64
+    }
+
+    /// Checks that the normals are the normalized positions.
+    fn check_sphere_normals(mesh: &Mesh, radius: f32) {
+        let normals = mesh.attribute(Mesh::ATTRIBUTE_NORMAL).unwrap();
+        let normals = normals.as_float3().unwrap();
+        for (position, normal) in positions(mesh).iter().zip(normals) {
+            let (position, normal) = (Vec3::from(*position), Vec3::from(*normal));
+            assert!((position.length() - radius).abs() < 1e-5);
+            assert!((normal.length() - 1.0).abs() < 1e-5);
+            assert!(normal.abs_diff_eq(position / radius, 1e-5));
+        }
+    }
+
+    #[test]
+    fn uv_sphere_topology() {
+        for (sectors, stacks) in [(3, 2), (8, 4), (36, 18), (7, 13)] {
+            let sphere = UVSphere {
+                radius: 2.0,
+                sectors,
+                stacks,
+                ..Default::default()
+            };
+            let mesh = Mesh::from(sphere);
+            assert_eq!(
+                euler_characteristic(&mesh),
+                2,
+                "{sectors} sectors, {stacks} stacks"
+            );
+            check_sphere_normals(&mesh, 2.0);
+        }
+    }
+
+    #[test]
+    fn icosphere_topology() {
+        for subdivisions in [0, 1, 2, 5, 12] {
+            let sphere = Icosphere {
+                radius: 2.0,
+                subdivisions,
+            };
+            let mesh = Mesh::try_from(sphere).unwrap();
+            // Vertices aren't duplicated at the UV seam.
+            assert_eq!(mesh.count_vertices(), 10 * (subdivisions + 1).pow(2) + 2);
+            assert_eq!(
+                euler_characteristic(&mesh),
+                2,
+                "{subdivisions} subdivisions"
+            );
+            check_sphere_normals(&mesh, 2.0);
+        }
+    }
+
+    /// Returns the generated tangents of the mesh, after checking that they are unit vectors
+    /// perpendicular to the normals with a handedness of `1.0` or `-1.0`.
+    fn check_tangents(mesh: &Mesh) -> &[[f32; 4]] {
+        let normals = mesh.attribute(Mesh::ATTRIBUTE_NORMAL).unwrap();
+        let normals = normals.as_float3().unwrap();
+        let Some(VertexAttributeValues::Float32x4(tangents)) =
+            mesh.attribute(Mesh::ATTRIBUTE_TANGENT)
+        else {
+            panic!("missing tangents");
+        };
+        assert_eq!(tangents.len(), normals.len());
+        for (tangent, normal) in tangents.iter().zip(normals) {
+            let [x, y, z, w] = *tangent;
+            let tangent = Vec3::new(x, y, z);
+            assert!((tangent.length() - 1.0).abs() < 1e-5, "{tangent}");
+            assert!(tangent.dot(Vec3::from(*normal)).abs() < 1e-5);
+            assert!(w == 1.0 || w == -1.0);
+        }
+        tangents
+    }
+
+    /// Checks that the generated tangents match those computed by [`Mesh::generate_tangents`],
+    /// except for the vertices whose UVs are `skip`ped. At the UV seam and poles of round shapes,
+    /// mikktspace only sees the triangles on one side of a vertex, so its tangents are off by up to
+    /// half a triangle.
+    fn check_mikktspace_tangents(mesh: Mesh, skip: impl Fn([f32; 2]) -> bool) {
+        let tangents = check_tangents(&mesh).to_vec();
+        let Some(VertexAttributeValues::Float32x2(uvs)) = mesh.attribute(Mesh::ATTRIBUTE_UV_0)
+        else {
+            panic!("unexpected uv format");
+        };
+        let uvs = uvs.clone();
+
+        let mut mikktspace = mesh;
+        mikktspace.remove_attribute(Mesh::ATTRIBUTE_TANGENT);
+        mikktspace.generate_tangents().unwrap();
+        let Some(VertexAttributeValues::Float32x4(expected)) =
+            mikktspace.attribute(Mesh::ATTRIBUTE_TANGENT)
+        else {
+            panic!("missing tangents");
+        };
+
+        for ((tangent, expected), uv) in tangents.iter().zip(expected).zip(uvs) {
+            if !skip(uv) {
+                let (tangent, expected) = (Vec4::from(*tangent), Vec4::from(*expected));
+                assert!(
+                    tangent.abs_diff_eq(expected, 1e-4),
+                    "{tangent} != {expected}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn generated_tangents() {
+        let plane = Plane {
+            subdivisions_x: 2,
+            uv_scale: Vec2::new(-2.0, 3.0),
+            skirt_depth: 0.5,
+            skirt_uvs: PlaneSkirtUvs::Extended,
+            ..Default::default()
+        };
+        check_mikktspace_tangents(Mesh::from(plane.with_generated_tangents()), |_| false);
+        let sphere = UVSphere::default().with_generated_tangents();
+        check_mikktspace_tangents(Mesh::from(sphere), |[u, v]| {
+            u == 0.0 || u == 1.0 || v == 0.0 || v == 1.0
+        });
+        let cylinder = Cylinder {
+            sweep_angle: 2.0,
+            ..Default::default()
+        };
+        check_mikktspace_tangents(Mesh::from(cylinder.with_generated_tangents()), |_| false);
+        let capsule = Capsule {
+            rings: 2,
+            ..Default::default()
+        };
+        check_mikktspace_tangents(Mesh::from(capsule.with_generated_tangents()), |[u, _]| {
+            u == 0.0 || u == 1.0
+        });
+
+        // mikktspace can't compute tangents for a skirt whose UVs don't change downwards.
+        let clamped = Plane {
+            skirt_uvs: PlaneSkirtUvs::Clamped,
+            ..plane
+        };
+        check_tangents(&Mesh::from(clamped.with_generated_tangents()));
+
+        assert!(Mesh::from(Plane::default())
+            .attribute(Mesh::ATTRIBUTE_TANGENT)
+            .is_none());
+    }
+
+    /// Returns the tangent and handedness shared by all vertices of each face of a box.
+    fn box_face_tangents(shape: Box) -> [Vec4; 6] {
+        let mesh = Mesh::from(shape);
+        let normals = mesh.attribute(Mesh::ATTRIBUTE_NORMAL).unwrap();
+        let normals = normals.as_float3().unwrap();
+        let tangents = check_tangents(&mesh);
+        BOX_NORMALS.map(|face_normal| {
+            let mut face_tangents = tangents
+                .iter()
+                .zip(normals)
+                .filter(|(_, normal)| Vec3::from(**normal) == face_normal)
+                .map(|(tangent, _)| Vec4::from(*tangent));
+            let tangent = face_tangents.next().unwrap();
+            assert!(face_tangents.all(|other| other == tangent));
+            tangent
+        })
+    }
+
+    #[test]
+    fn box_tangents() {
+        let mut shape = Box::new(1.0, 2.0, 3.0).with_generated_tangents();
+        shape.faces[2].subdivisions = UVec2::new(2, 3);
+        let tangents = box_face_tangents(shape);
+        // The UVs of some faces are mirrored, as seen from outside the box.
+        let expected = [
+            Vec4::new(1.0, 0.0, 0.0, -1.0),
+            Vec4::new(-1.0, 0.0, 0.0, 1.0),
+            Vec4::new(0.0, 1.0, 0.0, -1.0),
+            Vec4::new(0.0, -1.0, 0.0, 1.0),
+            Vec4::new(1.0, 0.0, 0.0, 1.0),
+            Vec4::new(-1.0, 0.0, 0.0, -1.0),
+        ];
+        assert_eq!(tangents, expected);
+        check_mikktspace_tangents(Mesh::from(shape), |_| false);
+
+        // Mirroring the texture on a face flips its handedness.
+        shape.faces[0].flip_x = true;
+        shape.faces[4].flip_y = true;
+        let flipped = box_face_tangents(shape);
+        assert_eq!(flipped[0], tangents[0] * Vec4::new(-1.0, -1.0, -1.0, -1.0));
+        assert_eq!(flipped[4], tangents[4] * Vec4::new(1.0, 1.0, 1.0, -1.0));
+        assert_eq!(flipped[1..4], tangents[1..4]);
+        assert_eq!(flipped[5], tangents[5]);
+        check_mikktspace_tangents(Mesh::from(shape), |_| false);
+    }
+
+    #[test]
+    fn inside_out_shapes() {
+        let uvs = |mesh: &Mesh| {
+            let Some(VertexAttributeValues::Float32x2(uvs)) = mesh.attribute(Mesh::ATTRIBUTE_UV_0)
+            else {
+                panic!("unexpected uv format");
+            };
+            uvs.clone()
+        };
+        let normals = |mesh: &Mesh| mesh.attribute(Mesh::ATTRIBUTE_NORMAL).unwrap().clone();
+
+        let box_shape = Box::new(1.0, 2.0, 3.0).with_generated_tangents();
+        let sphere = UVSphere::default().with_generated_tangents();
+        for (outside, inside) in [
+            (Mesh::from(box_shape), Mesh::from(box_shape.inside_out())),
+            (Mesh::from(sphere), Mesh::from(sphere.inside_out())),
+        ] {
+            assert_eq!(positions(&inside), positions(&outside));
+            assert_eq!(triangles(&inside).len(), triangles(&outside).len());
+
+            // The normals point inwards, and the triangles face the same way.
+            let (inside_normals, outside_normals) = (normals(&inside), normals(&outside));
+            let inside_normals = inside_normals.as_float3().unwrap();
+            for (inside, outside) in inside_normals
+                .iter()
+                .zip(outside_normals.as_float3().unwrap())
+            {
+                assert_eq!(Vec3::from(*inside), -Vec3::from(*outside));
+            }
+            let Some(Indices::U32(indices)) = inside.indices() else {
+                panic!("missing indices");
+            };
+            for (triangle, [a, b, c]) in indices.chunks_exact(3).zip(triangles(&inside)) {
+                let normal = Vec3::from(inside_normals[triangle[0] as usize]);
+                assert!((b - a).cross(c - a).dot(normal) > 0.0);
+            }
+
+            // The texture is mirrored horizontally, so it isn't seen backwards from the inside.
+            for (inside, outside) in uvs(&inside).iter().zip(uvs(&outside)) {
+                assert_eq!(*inside, [1.0 - outside[0], outside[1]]);
+            }
+        }
+
+        check_mikktspace_tangents(Mesh::from(box_shape.inside_out()), |_| false);
+        check_mikktspace_tangents(Mesh::from(sphere.inside_out()), |[u, v]| {
+            u == 0.0 || u == 1.0 || v == 0.0 || v == 1.0
+        });
+    }
+
+    #[test]
+    fn icosphere_too_many_subdivisions() {
+        let sphere = Icosphere {
+            radius: 1.0,
+            subdivisions: 80,
+        };
+        let Err(FromIcosphereError::TooManyVertices {
+            number_of_resulting_points,
+            ..
+        }) = Mesh::try_from(sphere)
+        else {
+            panic!("expected an error");
+        };
+        assert_eq!(number_of_resulting_points, 10 * 81 * 81 + 2);
+    }
+}
@@ -0,0 +1,330 @@
+use crate::mesh::{Indices, Mesh};
+use bevy_math::Vec2;
+use thiserror::Error;
+use wgpu::PrimitiveTopology;
+
+/// A simple polygon in the `XY` plane, which may be concave.
+///
+/// The vertices can be in either clockwise or counterclockwise order. Converting to a [`Mesh`]
+/// triangulates the polygon with ear clipping, and fails with [`FromPolygonError`] if the polygon
+/// intersects itself. The UVs map the bounding rectangle of the polygon to the whole texture.
+///
+/// Repeated consecutive vertices are ignored, so the border may be closed by repeating the first
+/// vertex at the end.
+///
+/// For triangles, use a polygon with three vertices.
+#[derive(Debug, Clone, Default)]
+pub struct Polygon {
+    /// The vertices along the border of the polygon.
+    pub vertices: Vec<Vec2>,
+}
+
+impl Polygon {
+    /// Creates a polygon in the `XY` plane from the vertices along its border.
+    pub fn new(vertices: impl IntoIterator<Item = Vec2>) -> Self {
+        Self {
+            vertices: vertices.into_iter().collect(),
+        }
+    }
+}
+
+/// An error when converting a [`Polygon`] to a [`Mesh`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum FromPolygonError {
+    /// The polygon has fewer than three distinct vertices.
+    #[error("A polygon needs at least 3 distinct vertices, but this one has {0}")]
+    TooFewVertices(usize),
+    /// All vertices of the polygon are on a line, or not finite.
+    #[error("The polygon has no area")]
+    ZeroArea,
+    /// Some edges of the polygon cross or touch each other.
+    #[error("The polygon intersects itself")]
+    SelfIntersecting,
+}
+
+impl TryFrom<Polygon> for Mesh {
+    type Error = FromPolygonError;
+
+    fn try_from(polygon: Polygon) -> Result<Self, Self::Error> {
+        let vertices = &polygon.vertices;
+        let indices = triangulate(vertices)?;
+
+        let (min, max) = vertices
+            .iter()
+            .fold((Vec2::MAX, Vec2::MIN), |(min, max), v| {
+                (min.min(*v), max.max(*v))
+            });
+        let size = max - min;
+
+        let positions: Vec<_> = vertices.iter().map(|v| [v.x, v.y, 0.0]).collect();
+        let normals = vec![[0.0, 0.0, 1.0]; vertices.len()];
+        let uvs: Vec<_> = vertices
+            .iter()
+            .map(|v| [(v.x - min.x) / size.x, (max.y - v.y) / size.y])
+            .collect();
+
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+        mesh.set_indices(Some(Indices::U32(indices)));
+        Ok(mesh)
+    }
+}
+
+/// Triangulates a simple polygon with ear clipping, returning counterclockwise triangles.
+fn triangulate(vertices: &[Vec2]) -> Result<Vec<u32>, FromPolygonError> {
+    // Skip repeated vertices, which would form edges of zero length that touch their neighbors.
+    let mut distinct: Vec<u32> = Vec::with_capacity(vertices.len());
+    for (i, vertex) in vertices.iter().enumerate() {
+        if distinct.last().map(|&last| vertices[last as usize]) != Some(*vertex) {
+            distinct.push(i as u32);
+        }
+    }
+    while distinct.len() > 1
+        && vertices[distinct[0] as usize] == vertices[distinct[distinct.len() - 1] as usize]
+    {
+        distinct.pop();
+    }
+    let points: Vec<Vec2> = distinct.iter().map(|&i| vertices[i as usize]).collect();
+
+    let len = points.len();
+    if len < 3 {
+        return Err(FromPolygonError::TooFewVertices(len));
+    }
+
+    // Twice the signed area, positive for counterclockwise polygons.
+    let area: f32 = (0..len)
+        .map(|i| points[i].perp_dot(points[(i + 1) % len]))
+        .sum();
+    if !(area.abs() > 0.0 && area.is_finite()) {
+        return Err(FromPolygonError::ZeroArea);
+    }
+    if is_self_intersecting(&points) {
+        return Err(FromPolygonError::SelfIntersecting);
+    }
+
+    let mut remaining = distinct;
+    if area < 0.0 {
+        remaining.reverse();
+    }
+    let mut indices = Vec::with_capacity((len - 2) * 3);
+
+    let mut i = 0;
+    let mut attempts = 0;
+    while remaining.len() > 3 {
+        let len = remaining.len();
+        let (prev, current, next) = (
+            remaining[(i + len - 1) % len],
+            remaining[i],
+            remaining[(i + 1) % len],
+        );
+        let [a, b, c] = [prev, current, next].map(|index| vertices[index as usize]);
+        let turn = (b - a).perp_dot(c - b);
+
+        // Vertices in the middle of a straight edge don't need a triangle.
+        let is_collinear = turn.abs() <= f32::EPSILON * (b - a).length() * (c - b).length();
+        let is_ear = turn > 0.0
+            && !remaining.iter().any(|&other| {
+                ![prev, current, next].contains(&other)
+                    && triangle_contains(a, b, c, vertices[other as usize])
+            });
+
+        if is_collinear || is_ear {
+            if is_ear {
+                indices.extend_from_slice(&[prev, current, next]);
+            }
+            remaining.remove(i);
+            if i == remaining.len() {
+                i = 0;
+            }
+            attempts = 0;
+        } else {
+            i = (i + 1) % len;
+            attempts += 1;
+            // Every simple polygon has an ear, so this only happens due to rounding errors.
+            if attempts > len {
+                return Err(FromPolygonError::SelfIntersecting);
+            }
+        }
+    }
+
+    let [a, b, c] = [0, 1, 2].map(|i| vertices[remaining[i] as usize]);
+    if (b - a).perp_dot(c - b) > 0.0 {
+        indices.extend_from_slice(&remaining);
+    }
+    Ok(indices)
+}
+
+/// Returns `true` if `point` is inside the counterclockwise triangle `abc` or on its border.
+fn triangle_contains(a: Vec2, b: Vec2, c: Vec2, point: Vec2) -> bool {
+    (b - a).perp_dot(point - a) >= 0.0
+        && (c - b).perp_dot(point - b) >= 0.0
+        && (a - c).perp_dot(point - c) >= 0.0
+}
+
+/// Returns `true` if any two edges that don't share a vertex cross or touch.
+fn is_self_intersecting(vertices: &[Vec2]) -> bool {
+    let len = vertices.len();
+    let edge = |i: usize| (vertices[i], vertices[(i + 1) % len]);
+    (0..len).any(|i| {
+        // Skip the edges next to edge `i`, including the one wrapping around.
+        let last = if i == 0 { len - 1 } else { len };
+        (i + 2..last).any(|j| segments_intersect(edge(i), edge(j)))
+    })
+}
+
+/// Returns `true` if the segments cross or touch.
+fn segments_intersect((p1, p2): (Vec2, Vec2), (q1, q2): (Vec2, Vec2)) -> bool {
+    let orientation = |a: Vec2, b: Vec2, point: Vec2| (b - a).perp_dot(point - a);
+    let on_segment =
+        |a: Vec2, b: Vec2, point: Vec2| point.cmpge(a.min(b)).all() && point.cmple(a.max(b)).all();
+
+    let (d1, d2) = (orientation(q1, q2, p1), orientation(q1, q2, p2));
+    let (d3, d4) = (orientation(p1, p2, q1), orientation(p1, p2, q2));
+    if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+        return true;
+    }
+    (d1 == 0.0 && on_segment(q1, q2, p1))
+        || (d2 == 0.0 && on_segment(q1, q2, p2))
+        || (d3 == 0.0 && on_segment(p1, p2, q1))
+        || (d4 == 0.0 && on_segment(p1, p2, q2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FromPolygonError, Polygon};
+    use crate::mesh::{Indices, Mesh, VertexAttributeValues};
+    use bevy_math::{Vec2, Vec3};
+
+    /// Triangulates the polygon, checks that the triangles face `+Z` and returns their total area.
+    fn triangulated_area(vertices: &[Vec2]) -> f32 {
+        let mesh = Mesh::try_from(Polygon::new(vertices.iter().copied())).unwrap();
+        assert_eq!(mesh.count_vertices(), vertices.len());
+        let Some(Indices::U32(indices)) = mesh.indices() else {
+            panic!("missing indices");
+        };
+
+        let mut area = 0.0;
+        for triangle in indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| vertices[triangle[i] as usize].extend(0.0));
+            let normal = (b - a).cross(c - a);
+            assert!(normal.dot(Vec3::Z) > 0.0, "triangle doesn't face +Z");
+            area += normal.length() / 2.0;
+        }
+        area
+    }
+
+    #[test]
+    fn concave_polygon() {
+        // An L shape, counterclockwise and clockwise
+        let mut l = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(1.0, 2.0),
+            Vec2::new(0.0, 2.0),
+        ];
+        assert_eq!(triangulated_area(&l), 3.0);
+        l.reverse();
+        assert_eq!(triangulated_area(&l), 3.0);
+
+        // Rotating the starting vertex doesn't matter.
+        l.rotate_left(2);
+        assert_eq!(triangulated_area(&l), 3.0);
+    }
+
+    #[test]
+    fn collinear_vertices() {
+        // A square with vertices in the middle of its edges, and a triangle
+        let mut square = [
+            Vec2::new(-1.0, -1.0),
+            Vec2::new(0.0, -1.0),
+            Vec2::new(1.0, -1.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(-1.0, 1.0),
+            Vec2::new(-1.0, 0.0),
+        ];
+        assert_eq!(triangulated_area(&square), 4.0);
+        square.reverse();
+        assert_eq!(triangulated_area(&square), 4.0);
+
+        let triangle = [Vec2::ZERO, Vec2::new(0.0, 2.0), Vec2::new(2.0, 0.0)];
+        assert_eq!(triangulated_area(&triangle), 2.0);
+    }
+
+    #[test]
+    fn repeated_vertices() {
+        // A square closed by repeating its first vertex, with a repeated corner
+        let mut square = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 2.0),
+            Vec2::new(0.0, 2.0),
+            Vec2::new(0.0, 0.0),
+        ];
+        assert_eq!(triangulated_area(&square), 4.0);
+        square.reverse();
+        assert_eq!(triangulated_area(&square), 4.0);
+        square.rotate_left(1);
+        assert_eq!(triangulated_area(&square), 4.0);
+
+        let segment = Polygon::new([Vec2::ZERO, Vec2::X, Vec2::X, Vec2::ZERO]);
+        assert_eq!(
+            Mesh::try_from(segment).unwrap_err(),
+            FromPolygonError::TooFewVertices(2)
+        );
+    }
+
+    #[test]
+    fn polygon_uvs() {
+        let triangle = Polygon::new([Vec2::new(-1.0, 0.0), Vec2::new(1.0, 0.0), Vec2::Y]);
+        let mesh = Mesh::try_from(triangle).unwrap();
+        let Some(VertexAttributeValues::Float32x2(uvs)) = mesh.attribute(Mesh::ATTRIBUTE_UV_0)
+        else {
+            panic!("unexpected uv format");
+        };
+        assert_eq!(uvs, &[[0.0, 1.0], [1.0, 1.0], [0.5, 0.0]]);
+    }
+
+    #[test]
+    fn invalid_polygons() {
+        let try_mesh = |vertices: &[Vec2]| Mesh::try_from(Polygon::new(vertices.iter().copied()));
+
+        assert_eq!(
+            try_mesh(&[Vec2::ZERO, Vec2::X]).unwrap_err(),
+            FromPolygonError::TooFewVertices(2)
+        );
+        assert_eq!(
+            try_mesh(&[Vec2::ZERO, Vec2::X, Vec2::new(2.0, 0.0)]).unwrap_err(),
+            FromPolygonError::ZeroArea
+        );
+
+        let bowtie = [
+            Vec2::ZERO,
+            Vec2::new(2.0, 2.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::Y,
+        ];
+        assert_eq!(
+            try_mesh(&bowtie).unwrap_err(),
+            FromPolygonError::SelfIntersecting
+        );
+
+        // A vertex touching another edge
+        let touching = [
+            Vec2::ZERO,
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 2.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.0, 2.0),
+        ];
+        assert_eq!(
+            try_mesh(&touching).unwrap_err(),
+            FromPolygonError::SelfIntersecting
+        );
+    }
+}
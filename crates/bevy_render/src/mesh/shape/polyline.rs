@@ -0,0 +1,222 @@
+use crate::mesh::Mesh;
+use bevy_math::Vec3;
+use wgpu::PrimitiveTopology;
+
+/// A path of connected line segments in 3D space.
+///
+/// Converting to a [`Mesh`] gives a [`PrimitiveTopology::LineStrip`], which is rendered one pixel
+/// wide by materials that support lines. Use [`Polyline::ribbon`] for a path with a width. A
+/// single line segment is a polyline with two vertices.
+///
+/// The `x` coordinate of the UVs is the distance along the path from its first vertex, which can
+/// be used to draw dashed lines in a shader.
+#[derive(Debug, Clone, Default)]
+pub struct Polyline {
+    /// The vertices along the path.
+    pub vertices: Vec<Vec3>,
+}
+
+impl Polyline {
+    /// Creates a path through the given vertices.
+    pub fn new(vertices: impl IntoIterator<Item = Vec3>) -> Self {
+        Self {
+            vertices: vertices.into_iter().collect(),
+        }
+    }
+
+    /// Expands the path into a flat strip of triangles with the given width, whose faces point
+    /// towards `facing`, for example towards the camera or upwards for a path on the ground.
+    pub fn ribbon(self, width: f32, facing: Vec3) -> PolylineRibbon {
+        PolylineRibbon {
+            polyline: self,
+            width,
+            facing,
+            miter_limit: 4.0,
+        }
+    }
+
+    /// The distance along the path from the first vertex to each vertex.
+    fn distances(&self) -> Vec<f32> {
+        let mut distance = 0.0;
+        let mut previous = self.vertices.first().copied().unwrap_or_default();
+        self.vertices
+            .iter()
+            .map(|&vertex| {
+                distance += vertex.distance(previous);
+                previous = vertex;
+                distance
+            })
+            .collect()
+    }
+}
+
+impl From<Polyline> for Mesh {
+    fn from(polyline: Polyline) -> Self {
+        debug_assert!(
+            polyline.vertices.len() > 1,
+            "A polyline requires at least 2 vertices."
+        );
+
+        let uvs: Vec<[f32; 2]> = polyline.distances().iter().map(|&d| [d, 0.0]).collect();
+        let positions: Vec<[f32; 3]> = polyline.vertices.iter().map(|v| v.to_array()).collect();
+
+        let mut mesh = Mesh::new(PrimitiveTopology::LineStrip);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+        mesh
+    }
+}
+
+/// A [`Polyline`] expanded into a flat strip with a width, created with [`Polyline::ribbon`].
+///
+/// Converting to a [`Mesh`] gives a [`PrimitiveTopology::TriangleStrip`] with a pair of vertices
+/// on either side of each vertex of the path. The `x` coordinate of the UVs is the distance along
+/// the path, and the `y` coordinate is 0 on the left side and 1 on the right side of the path, as
+/// seen from the `facing` direction.
+#[derive(Debug, Clone)]
+pub struct PolylineRibbon {
+    /// The path along the middle of the ribbon.
+    pub polyline: Polyline,
+    /// The width of the ribbon.
+    pub width: f32,
+    /// The direction the faces of the ribbon point towards.
+    pub facing: Vec3,
+    /// The longest a corner can reach out from the path, as a multiple of half the width.
+    ///
+    /// The sides of the ribbon are joined with miters at each corner, which get longer the sharper
+    /// the corner is. Longer miters are shortened to this length to avoid spikes.
+    pub miter_limit: f32,
+}
+
+impl From<PolylineRibbon> for Mesh {
+    fn from(ribbon: PolylineRibbon) -> Self {
+        let vertices = &ribbon.polyline.vertices;
+        debug_assert!(vertices.len() > 1, "A ribbon requires at least 2 vertices.");
+        debug_assert!(ribbon.miter_limit >= 1.0);
+
+        let facing = ribbon.facing.normalize();
+        let half_width = ribbon.width / 2.0;
+        // The direction to the left of each segment, as seen from the facing direction.
+        let sides: Vec<Vec3> = vertices
+            .windows(2)
+            .map(|segment| facing.cross(segment[1] - segment[0]).normalize_or_zero())
+            .collect();
+
+        let mut positions = Vec::with_capacity(vertices.len() * 2);
+        let mut uvs = Vec::with_capacity(vertices.len() * 2);
+        for (i, (vertex, distance)) in vertices.iter().zip(ribbon.polyline.distances()).enumerate()
+        {
+            let previous = sides[i.saturating_sub(1)];
+            let next = sides[i.min(sides.len() - 1)];
+
+            // The miter halves the angle between the segments, and is longer than half the width
+            // so that the sides stay parallel to the segments.
+            let miter = (previous + next).try_normalize().unwrap_or(next);
+            let cos = miter.dot(next);
+            let length = if cos > 1.0 / ribbon.miter_limit {
+                half_width / cos
+            } else {
+                half_width * ribbon.miter_limit
+            };
+
+            positions.extend([
+                (*vertex + miter * length).to_array(),
+                (*vertex - miter * length).to_array(),
+            ]);
+            uvs.extend([[distance, 0.0], [distance, 1.0]]);
+        }
+
+        let normals = vec![facing.to_array(); positions.len()];
+
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleStrip);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+        mesh
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Polyline;
+    use crate::mesh::{Mesh, VertexAttributeValues};
+    use bevy_math::Vec3;
+
+    fn positions(mesh: &Mesh) -> &[[f32; 3]] {
+        let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION).unwrap();
+        positions.as_float3().unwrap()
+    }
+
+    fn uvs(mesh: &Mesh) -> &[[f32; 2]] {
+        let Some(VertexAttributeValues::Float32x2(uvs)) = mesh.attribute(Mesh::ATTRIBUTE_UV_0)
+        else {
+            panic!("unexpected uv format");
+        };
+        uvs
+    }
+
+    fn zigzag() -> Polyline {
+        Polyline::new([
+            Vec3::ZERO,
+            Vec3::new(8.0, 0.0, 0.0),
+            Vec3::new(8.0, 0.0, -4.0),
+            Vec3::new(12.0, 0.0, 0.0),
+            // A very sharp corner
+            Vec3::new(4.0, 0.0, -3.6),
+        ])
+    }
+
+    #[test]
+    fn line_strip() {
+        let segment = Mesh::from(Polyline::new([Vec3::ZERO, Vec3::new(0.0, 3.0, 4.0)]));
+        assert_eq!(segment.count_vertices(), 2);
+        assert_eq!(uvs(&segment), &[[0.0, 0.0], [5.0, 0.0]]);
+
+        let mesh = Mesh::from(zigzag());
+        assert_eq!(mesh.count_vertices(), 5);
+        let distances: Vec<f32> = uvs(&mesh).iter().map(|uv| uv[0]).collect();
+        assert!(distances.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(distances[..3], [0.0, 8.0, 12.0]);
+    }
+
+    #[test]
+    fn ribbon() {
+        let polyline = zigzag();
+        let ribbon = polyline.clone().ribbon(0.5, Vec3::Y);
+        let mesh = Mesh::from(ribbon.clone());
+        assert_eq!(mesh.count_vertices(), 2 * polyline.vertices.len());
+
+        let positions = positions(&mesh);
+        let uvs = uvs(&mesh);
+        for (i, vertex) in polyline.vertices.iter().enumerate() {
+            // The sides are symmetric around the path, and share the distance along it.
+            let [left, right] = [0, 1].map(|side| Vec3::from(positions[2 * i + side]));
+            assert!(((left + right) / 2.0).abs_diff_eq(*vertex, 1e-6));
+            assert_eq!(uvs[2 * i][0], uvs[2 * i + 1][0]);
+            assert_eq!([uvs[2 * i][1], uvs[2 * i + 1][1]], [0.0, 1.0]);
+
+            // The ribbon is flat and as wide as requested, except for the miters.
+            assert!((left - right).dot(Vec3::Y).abs() < 1e-6);
+            let half_width = left.distance(*vertex);
+            assert!(half_width >= 0.25 - 1e-6);
+            assert!(half_width <= 0.25 * ribbon.miter_limit + 1e-6);
+        }
+        let distances: Vec<f32> = uvs.iter().step_by(2).map(|uv| uv[0]).collect();
+        assert!(distances.windows(2).all(|pair| pair[0] < pair[1]));
+
+        // The ends and the right angles aren't limited.
+        let half_width = |i: usize| Vec3::from(positions[2 * i]).distance(polyline.vertices[i]);
+        assert!((half_width(0) - 0.25).abs() < 1e-6);
+        assert!((half_width(1) - 0.25 * 2.0f32.sqrt()).abs() < 1e-6);
+        // The sharp corner is.
+        assert!((half_width(3) - 0.25 * ribbon.miter_limit).abs() < 1e-6);
+
+        // The triangles face upwards.
+        for i in 0..positions.len() - 2 {
+            let [a, b, c] = [0, 1, 2].map(|j| Vec3::from(positions[i + j]));
+            let normal = (b - a).cross(c - a);
+            let normal = if i % 2 == 0 { normal } else { -normal };
+            assert!(normal.y >= 0.0, "triangle {i} faces downwards");
+        }
+    }
+}
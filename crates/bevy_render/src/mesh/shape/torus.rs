@@ -9,6 +9,11 @@ pub struct Torus {
     pub ring_radius: f32,
     pub subdivisions_segments: usize,
     pub subdivisions_sides: usize,
+    /// The angle in radians that the torus sweeps around its axis, starting from the `+X` axis
+    /// towards `+Z`.
+    ///
+    /// The default is a full turn. Smaller angles build an arc of the torus with open ends.
+    pub arc_angle: f32,
 }
 
 impl Default for Torus {
@@ -18,6 +23,7 @@ impl Default for Torus {
             ring_radius: 0.5,
             subdivisions_segments: 32,
             subdivisions_sides: 24,
+            arc_angle: std::f32::consts::TAU,
         }
     }
 }
@@ -32,7 +38,7 @@ impl From<Torus> for Mesh {
         let mut normals: Vec<[f32; 3]> = Vec::with_capacity(n_vertices);
         let mut uvs: Vec<[f32; 2]> = Vec::with_capacity(n_vertices);
 
-        let segment_stride = 2.0 * std::f32::consts::PI / torus.subdivisions_segments as f32;
+        let segment_stride = torus.arc_angle / torus.subdivisions_segments as f32;
         let side_stride = 2.0 * std::f32::consts::PI / torus.subdivisions_sides as f32;
 
         for segment in 0..=torus.subdivisions_segments {
@@ -47,8 +53,9 @@ impl From<Torus> for Mesh {
                     theta.sin() * (torus.radius + torus.ring_radius * phi.cos()),
                 );
 
-                let center = Vec3::new(torus.radius * theta.cos(), 0., torus.radius * theta.sin());
-                let normal = (position - center).normalize();
+                // The direction from the center of the tube, which stays well-defined even when
+                // the ring radius is larger than the radius and the torus intersects itself.
+                let normal = Vec3::new(theta.cos() * phi.cos(), phi.sin(), theta.sin() * phi.cos());
 
                 positions.push(position.into());
                 normals.push(normal.into());
@@ -92,3 +99,63 @@ impl From<Torus> for Mesh {
         mesh
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Torus;
+    use crate::mesh::Mesh;
+
+    fn check_mesh(torus: Torus) {
+        let mesh = Mesh::from(torus);
+        let (segments, sides) = (torus.subdivisions_segments, torus.subdivisions_sides);
+        assert_eq!(mesh.count_vertices(), (segments + 1) * (sides + 1));
+        assert_eq!(mesh.indices().unwrap().len(), segments * sides * 6);
+
+        let normals = mesh.attribute(Mesh::ATTRIBUTE_NORMAL).unwrap();
+        for normal in normals.as_float3().unwrap() {
+            let length = bevy_math::Vec3::from(*normal).length();
+            assert!(
+                (length - 1.0).abs() < 1e-5,
+                "normal {normal:?} is not unit length"
+            );
+        }
+    }
+
+    #[test]
+    fn torus_mesh() {
+        check_mesh(Torus::default());
+        check_mesh(Torus {
+            subdivisions_segments: 5,
+            subdivisions_sides: 3,
+            arc_angle: std::f32::consts::FRAC_PI_2,
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn self_intersecting_torus_mesh() {
+        // Spindle and horn tori still have well-defined normals.
+        for ring_radius in [1.0, 1.5] {
+            check_mesh(Torus {
+                radius: 1.0,
+                ring_radius,
+                ..Default::default()
+            });
+        }
+    }
+
+    #[test]
+    fn torus_arc() {
+        let torus = Torus {
+            arc_angle: std::f32::consts::PI,
+            ..Default::default()
+        };
+        let mesh = Mesh::from(torus);
+        let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION).unwrap();
+        let positions = positions.as_float3().unwrap();
+        // The arc covers the half of the ring with positive z.
+        assert!(positions.iter().all(|position| position[2] >= -1e-5));
+        let last_ring = &positions[positions.len() - torus.subdivisions_sides - 1..];
+        assert!(last_ring.iter().all(|position| position[0] < 0.0));
+    }
+}
@@ -1,9 +1,15 @@
 use wgpu::PrimitiveTopology;
 
 use crate::mesh::{Indices, Mesh};
+use bevy_math::Vec3;
 use std::f32::consts::PI;
 
 /// A sphere made of sectors and stacks.
+///
+/// The UVs use an equirectangular projection. The seam and the poles have a separate vertex for
+/// each sector, so the texture doesn't wrap around backwards, but the vertices are packed more
+/// densely towards the poles. For vertices spread evenly over the surface, use an
+/// [`Icosphere`](super::Icosphere).
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Clone, Copy)]
 pub struct UVSphere {
@@ -13,6 +19,14 @@ pub struct UVSphere {
     pub sectors: usize,
     /// Latitudinal stacks
     pub stacks: usize,
+    /// Whether to add [`Mesh::ATTRIBUTE_TANGENT`] to the mesh, for normal mapping.
+    pub generate_tangents: bool,
+    /// Whether the faces point into the sphere, so that it is visible from the inside, for
+    /// example for a skybox.
+    ///
+    /// The texture is mirrored horizontally, so that it reads the same from the inside as the
+    /// outside of a regular sphere.
+    pub inside_out: bool,
 }
 
 impl Default for UVSphere {
@@ -21,10 +35,26 @@ impl Default for UVSphere {
             radius: 1.0,
             sectors: 36,
             stacks: 18,
+            generate_tangents: false,
+            inside_out: false,
         }
     }
 }
 
+impl UVSphere {
+    /// Adds [`Mesh::ATTRIBUTE_TANGENT`] to the mesh, for normal mapping.
+    pub fn with_generated_tangents(mut self) -> Self {
+        self.generate_tangents = true;
+        self
+    }
+
+    /// Turns the faces of the sphere inwards, so that it is visible from the inside.
+    pub fn inside_out(mut self) -> Self {
+        self.inside_out = true;
+        self
+    }
+}
+
 impl From<UVSphere> for Mesh {
     fn from(sphere: UVSphere) -> Self {
         // Largely inspired from http://www.songho.ca/opengl/gl_sphere.html
@@ -32,12 +62,15 @@ impl From<UVSphere> for Mesh {
         let sectors = sphere.sectors as f32;
         let stacks = sphere.stacks as f32;
         let length_inv = 1. / sphere.radius;
+        // Flips the normals, and the direction in which `u` increases.
+        let side = if sphere.inside_out { -1. } else { 1. };
         let sector_step = 2. * PI / sectors;
         let stack_step = PI / stacks;
 
         let mut vertices: Vec<[f32; 3]> = Vec::with_capacity(sphere.stacks * sphere.sectors);
         let mut normals: Vec<[f32; 3]> = Vec::with_capacity(sphere.stacks * sphere.sectors);
         let mut uvs: Vec<[f32; 2]> = Vec::with_capacity(sphere.stacks * sphere.sectors);
+        let mut tangents: Vec<[f32; 4]> = Vec::new();
         let mut indices: Vec<u32> = Vec::with_capacity(sphere.stacks * sphere.sectors * 2 * 3);
 
         for i in 0..sphere.stacks + 1 {
@@ -51,8 +84,21 @@ impl From<UVSphere> for Mesh {
                 let y = xy * sector_angle.sin();
 
                 vertices.push([x, y, z]);
-                normals.push([x * length_inv, y * length_inv, z * length_inv]);
-                uvs.push([(j as f32) / sectors, (i as f32) / stacks]);
+                let normal = Vec3::new(x, y, z) * length_inv * side;
+                normals.push(normal.to_array());
+                let u = (j as f32) / sectors;
+                let u = if sphere.inside_out { 1. - u } else { u };
+                uvs.push([u, (i as f32) / stacks]);
+
+                if sphere.generate_tangents {
+                    // u increases eastwards around the Z axis, and v southwards along the
+                    // meridians, which also gives the poles a tangent for each sector.
+                    let (sin, cos) = sector_angle.sin_cos();
+                    let du = Vec3::new(-sin, cos, 0.0) * side;
+                    let (stack_sin, stack_cos) = stack_angle.sin_cos();
+                    let dv = Vec3::new(stack_sin * cos, stack_sin * sin, -stack_cos);
+                    tangents.push(super::tangent(normal, du, dv));
+                }
             }
         }
 
@@ -80,11 +126,18 @@ impl From<UVSphere> for Mesh {
             }
         }
 
+        if sphere.inside_out {
+            indices.chunks_exact_mut(3).for_each(|t| t.swap(1, 2));
+        }
+
         let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
         mesh.set_indices(Some(Indices::U32(indices)));
         mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
         mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
         mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+        if sphere.generate_tangents {
+            mesh.insert_attribute(Mesh::ATTRIBUTE_TANGENT, tangents);
+        }
         mesh
     }
 }
@@ -13,54 +13,132 @@ use std::{
 ///
 /// Wrapping a float with `FloatOrd` breaks conformance with the standard
 /// by sorting `NaN` as less than all other numbers and equal to any other `NaN`.
-#[derive(Debug, Copy, Clone, PartialOrd)]
+///
+/// For [`f64`], use [`FloatOrd64`].
+#[derive(Debug, Copy, Clone)]
 pub struct FloatOrd(pub f32);
 
-#[allow(clippy::derive_ord_xor_partial_ord)]
-impl Ord for FloatOrd {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.0.partial_cmp(&other.0).unwrap_or_else(|| {
-            if self.0.is_nan() && !other.0.is_nan() {
-                Ordering::Less
-            } else if !self.0.is_nan() && other.0.is_nan() {
-                Ordering::Greater
-            } else {
-                Ordering::Equal
+/// A wrapper for [`f64`] that implements [`Ord`], [`Eq`], and [`Hash`] traits.
+///
+/// This is the [`f64`] version of [`FloatOrd`], and orders values the same way:
+/// `NaN` is sorted as less than all other numbers and equal to any other `NaN`.
+#[derive(Debug, Copy, Clone)]
+pub struct FloatOrd64(pub f64);
+
+macro_rules! impl_float_ord {
+    ($float_ord:ident, $float:ty) => {
+        // Comparison operators such as `<` must agree with `Ord`, which sorting relies on.
+        impl PartialOrd for $float_ord {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
             }
-        })
-    }
-}
+        }
 
-impl PartialEq for FloatOrd {
-    fn eq(&self, other: &Self) -> bool {
-        if self.0.is_nan() && other.0.is_nan() {
-            true
-        } else {
-            self.0 == other.0
+        impl Ord for $float_ord {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.partial_cmp(&other.0).unwrap_or_else(|| {
+                    if self.0.is_nan() && !other.0.is_nan() {
+                        Ordering::Less
+                    } else if !self.0.is_nan() && other.0.is_nan() {
+                        Ordering::Greater
+                    } else {
+                        Ordering::Equal
+                    }
+                })
+            }
         }
-    }
-}
 
-impl Eq for FloatOrd {}
-
-impl Hash for FloatOrd {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        if self.0.is_nan() {
-            // Ensure all NaN representations hash to the same value
-            state.write(&f32::to_ne_bytes(f32::NAN));
-        } else if self.0 == 0.0 {
-            // Ensure both zeroes hash to the same value
-            state.write(&f32::to_ne_bytes(0.0f32));
-        } else {
-            state.write(&f32::to_ne_bytes(self.0));
+        impl PartialEq for $float_ord {
+            fn eq(&self, other: &Self) -> bool {
+                if self.0.is_nan() && other.0.is_nan() {
+                    true
+                } else {
+                    self.0 == other.0
+                }
+            }
         }
-    }
+
+        impl Eq for $float_ord {}
+
+        impl Hash for $float_ord {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                if self.0.is_nan() {
+                    // Ensure all NaN representations hash to the same value
+                    state.write(&<$float>::to_ne_bytes(<$float>::NAN));
+                } else if self.0 == 0.0 {
+                    // Ensure both zeroes hash to the same value
+                    state.write(&<$float>::to_ne_bytes(0.0));
+                } else {
+                    state.write(&<$float>::to_ne_bytes(self.0));
+                }
+            }
+        }
+
+        impl Neg for $float_ord {
+            type Output = $float_ord;
+
+            fn neg(self) -> Self::Output {
+                $float_ord(-self.0)
+            }
+        }
+    };
 }
 
-impl Neg for FloatOrd {
-    type Output = FloatOrd;
+impl_float_ord!(FloatOrd, f32);
+impl_float_ord!(FloatOrd64, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+
+    fn hash<T: Hash>(value: T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn total_order() {
+        let mut values = [
+            f64::INFINITY,
+            1.0,
+            f64::NAN,
+            -0.0,
+            f64::NEG_INFINITY,
+            -f64::NAN,
+            0.0,
+        ]
+        .map(FloatOrd64);
+        values.sort();
+        let sorted = values.map(|value| value.0);
+        assert!(sorted[0].is_nan() && sorted[1].is_nan());
+        assert_eq!(
+            sorted[2..],
+            [f64::NEG_INFINITY, -0.0, 0.0, 1.0, f64::INFINITY]
+        );
+
+        // Matches the f32 version
+        let mut values_32 = [f32::INFINITY, f32::NAN, -1.0, f32::NEG_INFINITY].map(FloatOrd);
+        values_32.sort();
+        assert!(values_32[0].0.is_nan());
+        let sorted_32 = values_32.map(|value| value.0);
+        assert!(FloatOrd(f32::NAN) < FloatOrd(f32::NEG_INFINITY));
+        assert_eq!(sorted_32[1..], [f32::NEG_INFINITY, -1.0, f32::INFINITY]);
+    }
+
+    #[test]
+    fn equal_values_hash_equally() {
+        assert_eq!(FloatOrd64(0.0), FloatOrd64(-0.0));
+        assert_eq!(hash(FloatOrd64(0.0)), hash(FloatOrd64(-0.0)));
+
+        let other_nan = f64::from_bits(f64::NAN.to_bits() ^ 1);
+        assert!(other_nan.is_nan());
+        assert_eq!(FloatOrd64(f64::NAN), FloatOrd64(other_nan));
+        assert_eq!(hash(FloatOrd64(f64::NAN)), hash(FloatOrd64(other_nan)));
+        assert_eq!(hash(FloatOrd64(f64::NAN)), hash(FloatOrd64(-f64::NAN)));
 
-    fn neg(self) -> Self::Output {
-        FloatOrd(-self.0)
+        assert_eq!(hash(FloatOrd(0.0)), hash(FloatOrd(-0.0)));
+        assert_eq!(hash(FloatOrd(f32::NAN)), hash(FloatOrd(-f32::NAN)));
     }
 }
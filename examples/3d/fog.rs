@@ -65,6 +65,7 @@ fn setup_pyramid_scene(
                 max_z: 0.5,
                 min_y: 0.0,
                 max_y: 3.0,
+                ..default()
             })),
             material: stone.clone(),
             transform: Transform::from_xyz(*x, 0.0, *z),
@@ -104,6 +105,7 @@ fn setup_pyramid_scene(
                 max_z: size,
                 min_y: 0.0,
                 max_y: 0.5,
+                ..default()
             })),
             material: stone.clone(),
             transform: Transform::from_xyz(0.0, y, 0.0),
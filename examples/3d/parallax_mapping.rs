@@ -247,11 +247,7 @@ fn setup(
     // Plane
     commands.spawn(PbrBundle {
         mesh: meshes.add(
-            shape::Plane {
-                size: 10.0,
-                subdivisions: 0,
-            }
-            .into(),
+            shape::Plane::from_size(10.0).into(),
         ),
         material: materials.add(StandardMaterial {
             // standard material derived from dark green, but
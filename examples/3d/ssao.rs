@@ -71,6 +71,7 @@ fn setup(
                 radius: 0.4,
                 sectors: 72,
                 stacks: 36,
+                ..default()
             })),
             material: materials.add(StandardMaterial {
                 base_color: Color::rgb(0.4, 0.4, 0.4),
@@ -82,8 +82,9 @@ pub fn animate_cube(
     for (mut transform, cubic_curve) in &mut query {
         // Draw the curve
         gizmos.linestrip(cubic_curve.0.iter_positions(50), Color::WHITE);
-        // position takes a point from the curve where 0 is the initial point
-        // and 1 is the last point
-        transform.translation = cubic_curve.0.position(t);
+        // position_at_distance takes a point at the given distance along the curve,
+        // which makes the cube move at a constant speed, unlike `position(t)`
+        let distance = t * cubic_curve.0.arc_length();
+        transform.translation = cubic_curve.0.position_at_distance(distance);
     }
 }